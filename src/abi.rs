@@ -0,0 +1,82 @@
+use std::fmt;
+
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// ABI of the target that further disambiguates the target platform beyond its [`Env`](crate::Env).
+    ///
+    /// # Note
+    /// This value is closely related to the suffix of the fourth element of the platform target triple,
+    /// though it is not identical. For example, `armv7-unknown-linux-gnueabihf` defines `target_abi` as
+    /// `"eabihf"` (i.e. [`Abi::EabiHf`]) and `aarch64-apple-ios-sim` as `"sim"` (i.e. [`Abi::Sim`]).
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum Abi {
+        /// MIPS 64-bit ABI (N64)
+        Abi64 => "abi64",
+        /// C-SKY ABI version 2
+        AbiV2 => "abiv2",
+        /// C-SKY ABI version 2 with hardware floating point
+        AbiV2Hf => "abiv2hf",
+        /// ARM Embedded ABI with software floating point calling convention
+        Eabi => "eabi",
+        /// ARM Embedded ABI with hardware floating point calling convention
+        EabiHf => "eabihf",
+        /// PowerPC 64-bit ELF ABI version 1
+        ElfV1 => "elfv1",
+        /// PowerPC 64-bit ELF ABI version 2
+        ElfV2 => "elfv2",
+        /// Fortanix Enclave Development Platform
+        Fortanix => "fortanix",
+        /// 32-bit pointers on a 64-bit architecture (AArch64 ILP32)
+        Ilp32 => "ilp32",
+        /// RISC-V embedded ABI with 16 general purpose registers
+        Ilp32e => "ilp32e",
+        /// LLVM based toolchain (e.g. `gnullvm` using LLVM's libunwind and compiler-rt)
+        Llvm => "llvm",
+        /// Mac Catalyst, iOS apps running on macOS
+        MacAbi => "macabi",
+        /// Apple platform simulator
+        Sim => "sim",
+        /// Floating point values are passed in integer registers
+        SoftFloat => "softfloat",
+        /// PowerPC Signal Processing Engine
+        Spe => "spe",
+        /// Universal Windows Platform
+        Uwp => "uwp",
+        /// AIX ABI with extended vector support
+        VecExtAbi => "vec-extabi",
+        /// 32-bit pointers on x86-64
+        X32 => "x32",
+    }
+
+    as_str_doc = "String representing this ABI which matches `#[cfg(target_abi)]`.",
+    from_str_doc = "Tries to parse the given string as an [`Abi`] falling back to [`Abi::Other`] for unknown values.",
+}
+
+impl Abi {
+    /// Gets the current target [`Abi`].
+    #[must_use]
+    pub fn target() -> Option<Self> {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`Abi`].
+    pub fn try_target() -> Result<Option<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`Abi`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Option<Self>, Error> {
+        build_env_opt(env, "CARGO_CFG_TARGET_ABI").map(|value| value.map(Self::from_str))
+    }
+}
+
+impl fmt::Display for Abi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
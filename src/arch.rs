@@ -1,4 +1,7 @@
-use crate::utils::{build_env, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env, define_target_enum, expect_env},
+};
 use std::fmt;
 
 define_target_enum! {
@@ -22,6 +25,8 @@ define_target_enum! {
         Csky => "csky",
         /// Qualcomm Hexagon DSP architecture
         Hexagon => "hexagon",
+        /// LoongArch 32-bit CPU architecture
+        Loongarch32 => "loongarch32",
         /// LoongArch 64-bit CPU architecture
         Loongarch64 => "loongarch64",
         /// Motorola 68k CPU architecture
@@ -72,7 +77,17 @@ impl Arch {
     /// Gets the current target [`Arch`].
     #[must_use]
     pub fn target() -> Self {
-        Self::from_str(build_env("CARGO_CFG_TARGET_ARCH"))
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`Arch`].
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`Arch`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "CARGO_CFG_TARGET_ARCH").map(Self::from_str)
     }
 }
 
@@ -0,0 +1,432 @@
+use std::fmt;
+
+use crate::{
+    Abi, Arch, Endian, Env, EnvSource, Error, Os, ProcessEnv, SubArch, Target, TargetFeature,
+    TargetFeatures, Triple, Vendor,
+    utils::{define_target_enum, expect_env},
+};
+
+/// Built-in targets with an `arm` triple which generate Thumb code by default. Stable compilers do not report
+/// the `thumb-mode` target feature, so it cannot be detected otherwise.
+const THUMB_MODE_TARGETS: &[&str] = &["armv7-linux-androideabi"];
+
+define_target_enum! {
+    /// Profile of an ARM architecture version.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum ArmProfile {
+        /// Application profile for cores running rich operating systems (Cortex-A)
+        Application => "a",
+        /// Real-time profile for deterministic embedded systems (Cortex-R)
+        RealTime => "r",
+        /// Microcontroller profile which only supports the Thumb instruction set (Cortex-M)
+        Microcontroller => "m",
+    }
+
+    as_str_doc = "String representing this profile as used in `-march`, e.g. `m` in `armv7-m`.",
+    from_str_doc = "Tries to parse the given string as an [`ArmProfile`] falling back to [`ArmProfile::Other`] for unknown values.",
+}
+
+impl fmt::Display for ArmProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+define_target_enum! {
+    /// Calling convention used for floating point values on ARM.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum ArmFloatAbi {
+        /// Floating point operations are emulated and values are passed in integer registers.
+        Soft => "soft",
+        /// Floating point instructions are used, but values are passed in integer registers.
+        SoftFp => "softfp",
+        /// Floating point instructions are used and values are passed in floating point registers.
+        Hard => "hard",
+    }
+
+    as_str_doc = "String representing this float ABI as passed to `-mfloat-abi`.",
+    from_str_doc = "Tries to parse the given string as an [`ArmFloatAbi`] falling back to [`ArmFloatAbi::Other`] for unknown values.",
+}
+
+impl fmt::Display for ArmFloatAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+define_target_enum! {
+    /// Floating point unit of an ARM target.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum ArmFpu {
+        /// VFPv2
+        Vfpv2 => "vfpv2",
+        /// VFPv3 with 16 double precision registers
+        Vfpv3D16 => "vfpv3-d16",
+        /// VFPv3 with 32 double precision registers
+        Vfpv3 => "vfpv3",
+        /// VFPv4 with 16 double precision registers
+        Vfpv4D16 => "vfpv4-d16",
+        /// VFPv4 with 32 double precision registers
+        Vfpv4 => "vfpv4",
+        /// Single precision VFPv4 of the Cortex-M4 and M7
+        FpV4SpD16 => "fpv4-sp-d16",
+        /// Single precision ARMv8 floating point of the Cortex-M33 and R52
+        FpV5SpD16 => "fpv5-sp-d16",
+        /// ARMv8 floating point with 16 double precision registers
+        FpV5D16 => "fpv5-d16",
+        /// ARMv8 floating point with 32 double precision registers
+        FpArmv8 => "fp-armv8",
+        /// VFPv3 with NEON
+        Neon => "neon",
+        /// VFPv4 with NEON
+        NeonVfpv4 => "neon-vfpv4",
+        /// ARMv8 floating point with NEON
+        NeonFpArmv8 => "neon-fp-armv8",
+    }
+
+    as_str_doc = "String representing this FPU as passed to `-mfpu`.",
+    from_str_doc = "Tries to parse the given string as an [`ArmFpu`] falling back to [`ArmFpu::Other`] for unknown values.",
+}
+
+impl ArmFpu {
+    /// Checks whether this FPU includes the NEON SIMD extension.
+    #[must_use]
+    pub fn has_neon(&self) -> bool {
+        matches!(self, Self::Neon | Self::NeonVfpv4 | Self::NeonFpArmv8)
+    }
+
+    /// Checks whether this FPU supports double precision operations.
+    #[must_use]
+    pub fn has_double_precision(&self) -> bool {
+        !matches!(self, Self::FpV4SpD16 | Self::FpV5SpD16 | Self::Other(_))
+    }
+}
+
+impl fmt::Display for ArmFpu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Architecture details of a 32-bit ARM target ([`Arch::Arm`]) as needed to pick `-march`, `-mfpu` and
+/// `-mfloat-abi` when compiling C code for it.
+///
+/// The information is derived from the triple, which fully determines the defaults of the built-in targets,
+/// and refined by the enabled target features. Stable compilers do not report ARM target features, so
+/// features enabled through `-C target-feature` are only considered on nightly.
+///
+/// # Example
+/// ```rust
+/// use build_target::{ArmFloatAbi, ArmFpu, ArmProfile, Target};
+///
+/// let arm = Target::lookup("thumbv7em-none-eabihf").unwrap().arm_info().unwrap();
+/// assert_eq!(arm.version(), 7);
+/// assert_eq!(arm.profile, Some(ArmProfile::Microcontroller));
+/// assert!(arm.thumb_only);
+/// assert_eq!(arm.float_abi, ArmFloatAbi::Hard);
+/// assert_eq!(arm.fpu, Some(ArmFpu::FpV4SpD16));
+/// assert_eq!(arm.march(), "armv7e-m");
+///
+/// let arm = Target::lookup("armv7-unknown-linux-gnueabihf").unwrap().arm_info().unwrap();
+/// assert_eq!(arm.profile, Some(ArmProfile::Application));
+/// assert!(!arm.thumb_mode);
+/// assert_eq!(arm.fpu, Some(ArmFpu::Vfpv3D16));
+/// assert!(!arm.has_neon());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ArmInfo {
+    /// The architecture version, e.g. [`SubArch::ArmV7em`].
+    pub sub_arch: SubArch,
+    /// The profile of the architecture, or [`None`] for versions before ARMv7 which predate profiles.
+    pub profile: Option<ArmProfile>,
+    /// Whether code is generated as Thumb instead of ARM instructions by default.
+    pub thumb_mode: bool,
+    /// Whether the architecture only supports the Thumb instruction set.
+    pub thumb_only: bool,
+    /// The calling convention used for floating point values.
+    pub float_abi: ArmFloatAbi,
+    /// The floating point unit available to generated code, or [`None`] if floating point operations are
+    /// emulated.
+    pub fpu: Option<ArmFpu>,
+}
+
+impl ArmInfo {
+    /// Gets the [`ArmInfo`] of the current target or [`None`] if it is not a 32-bit ARM target.
+    #[must_use]
+    pub fn target() -> Option<Self> {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the [`ArmInfo`] of the current target.
+    pub fn try_target() -> Result<Option<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`ArmInfo`] of the target described by the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Option<Self>, Error> {
+        Target::from_env(env).map(|target| Self::from_target(&target))
+    }
+
+    /// Gets the [`ArmInfo`] of the given target or [`None`] if it is not a 32-bit ARM target.
+    #[must_use]
+    pub fn from_target(target: &Target) -> Option<Self> {
+        if target.arch != Arch::Arm {
+            return None;
+        }
+
+        let triple = Triple::parse(&target.triple).ok();
+        let sub_arch = triple
+            .as_ref()
+            .and_then(Triple::sub_arch)
+            .unwrap_or_else(|| {
+                // the versions of the built-in `arm` and `armeb` targets
+                if target.os == Os::Android {
+                    SubArch::ArmV5te
+                } else if target.endian == Endian::Big {
+                    SubArch::ArmV8
+                } else {
+                    SubArch::ArmV6
+                }
+            });
+        let profile = match sub_arch {
+            SubArch::ArmV6m
+            | SubArch::ArmV7m
+            | SubArch::ArmV7em
+            | SubArch::ArmV8mBase
+            | SubArch::ArmV8mMain => Some(ArmProfile::Microcontroller),
+            SubArch::ArmV7r | SubArch::ArmV8r => Some(ArmProfile::RealTime),
+            SubArch::ArmV4t | SubArch::ArmV5te | SubArch::ArmV6 | SubArch::ArmV6k => None,
+            _ => Some(ArmProfile::Application),
+        };
+        let thumb_only = profile == Some(ArmProfile::Microcontroller);
+        let thumb_mode = thumb_only
+            || target.features.contains(&TargetFeature::ThumbMode)
+            || THUMB_MODE_TARGETS.contains(&target.triple.as_str())
+            || triple.is_some_and(|triple| triple.arch.starts_with("thumb"));
+
+        let hard_float = target.abi == Some(Abi::EabiHf)
+            || target.os == Os::Windows
+            || sub_arch == SubArch::ArmV7k;
+        // soft-float targets other than those of Android, OpenHarmony and Apple do not use an FPU at all
+        let uses_fpu = hard_float
+            || target.os == Os::Android
+            || target.env == Some(Env::OhOS)
+            || target.vendor == Vendor::Apple;
+        let default_fpu = match sub_arch {
+            _ if !uses_fpu => None,
+            SubArch::ArmV6 | SubArch::ArmV6k => Some(ArmFpu::Vfpv2),
+            SubArch::ArmV7 | SubArch::ArmV7a
+                if matches!(target.os, Os::Windows | Os::Vita | Os::VexOS) =>
+            {
+                Some(ArmFpu::Neon)
+            }
+            SubArch::ArmV7 | SubArch::ArmV7a | SubArch::ArmV7r => Some(ArmFpu::Vfpv3D16),
+            SubArch::ArmV7neon => Some(ArmFpu::Neon),
+            SubArch::ArmV7k | SubArch::ArmV7s => Some(ArmFpu::NeonVfpv4),
+            SubArch::ArmV7em => Some(ArmFpu::FpV4SpD16),
+            SubArch::ArmV8mMain | SubArch::ArmV8r => Some(ArmFpu::FpV5SpD16),
+            _ => None,
+        };
+        let fpu = Self::fpu_from_features(&target.features, default_fpu);
+
+        let float_abi = if hard_float {
+            ArmFloatAbi::Hard
+        } else if fpu.is_some() && !target.features.contains(&TargetFeature::SoftFloat) {
+            ArmFloatAbi::SoftFp
+        } else {
+            ArmFloatAbi::Soft
+        };
+
+        Some(Self {
+            sub_arch,
+            profile,
+            thumb_mode,
+            thumb_only,
+            float_abi,
+            fpu,
+        })
+    }
+
+    /// Derives the FPU from the enabled floating point target features, keeping the given default if none are
+    /// enabled or the features do not tell it apart from a more specific default.
+    fn fpu_from_features(features: &TargetFeatures, default: Option<ArmFpu>) -> Option<ArmFpu> {
+        let neon = features.contains(&TargetFeature::Neon);
+        let d32 = features.contains(&TargetFeature::D32);
+        if features.contains(&TargetFeature::FpArmv8) {
+            Some(if neon {
+                ArmFpu::NeonFpArmv8
+            } else if d32 {
+                ArmFpu::FpArmv8
+            } else if default == Some(ArmFpu::FpV5SpD16) {
+                ArmFpu::FpV5SpD16
+            } else {
+                ArmFpu::FpV5D16
+            })
+        } else if features.contains(&TargetFeature::Vfp4) {
+            Some(if neon {
+                ArmFpu::NeonVfpv4
+            } else if d32 {
+                ArmFpu::Vfpv4
+            } else if default == Some(ArmFpu::FpV4SpD16) {
+                ArmFpu::FpV4SpD16
+            } else {
+                ArmFpu::Vfpv4D16
+            })
+        } else if features.contains(&TargetFeature::Vfp3) {
+            Some(if neon {
+                ArmFpu::Neon
+            } else if d32 {
+                ArmFpu::Vfpv3
+            } else {
+                ArmFpu::Vfpv3D16
+            })
+        } else if features.contains(&TargetFeature::Vfp2) {
+            Some(ArmFpu::Vfpv2)
+        } else {
+            default
+        }
+    }
+
+    /// Gets the major architecture version, e.g. `7` for ARMv7E-M.
+    #[must_use]
+    pub fn version(&self) -> u8 {
+        match self.sub_arch.as_str().as_bytes() {
+            [b'v', version @ b'0'..=b'9', ..] => version - b'0',
+            _ => 0,
+        }
+    }
+
+    /// Gets the architecture as passed to `-march`, such as `armv7-a`, `armv7e-m` or `armv8-m.main`.
+    #[must_use]
+    pub fn march(&self) -> String {
+        match self.sub_arch {
+            SubArch::ArmV7 | SubArch::ArmV7neon | SubArch::ArmV7k | SubArch::ArmV7s => {
+                "armv7-a".to_string()
+            }
+            SubArch::ArmV7em => "armv7e-m".to_string(),
+            SubArch::ArmV8 => "armv8-a".to_string(),
+            _ => match (&self.profile, self.sub_arch.as_str().split_at_checked(2)) {
+                // e.g. `v8m.main` becomes `armv8-m.main`
+                (Some(profile), Some((version, extension))) => {
+                    let extension = extension.trim_start_matches(profile.as_str());
+                    format!("arm{version}-{profile}{extension}")
+                }
+                _ => format!("arm{}", self.sub_arch),
+            },
+        }
+    }
+
+    /// Checks whether floating point values are passed in floating point registers.
+    #[must_use]
+    pub fn is_hard_float(&self) -> bool {
+        self.float_abi == ArmFloatAbi::Hard
+    }
+
+    /// Checks whether the NEON SIMD extension is available.
+    #[must_use]
+    pub fn has_neon(&self) -> bool {
+        self.fpu.as_ref().is_some_and(ArmFpu::has_neon)
+    }
+}
+
+impl Target {
+    /// Gets the [`ArmInfo`] of this target or [`None`] if it is not a 32-bit ARM target.
+    /// See [`ArmInfo::from_target()`].
+    #[must_use]
+    pub fn arm_info(&self) -> Option<ArmInfo> {
+        ArmInfo::from_target(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arm_info(triple: &str) -> ArmInfo {
+        Target::lookup(triple).unwrap().arm_info().unwrap()
+    }
+
+    #[test]
+    fn all_arm_targets() {
+        for target in crate::targets::all()
+            .iter()
+            .filter(|target| target.arch == Arch::Arm)
+        {
+            let arm = target.arm_info().unwrap();
+            assert!((4..=8).contains(&arm.version()), "{}", target.triple);
+            assert_eq!(
+                arm.is_hard_float(),
+                target.abi == Some(Abi::EabiHf)
+                    || target.os == Os::Windows
+                    || target.triple.starts_with("armv7k"),
+                "{}",
+                target.triple
+            );
+        }
+        assert_eq!(
+            Target::lookup("aarch64-unknown-linux-gnu")
+                .unwrap()
+                .arm_info(),
+            None
+        );
+    }
+
+    #[test]
+    fn microcontrollers() {
+        let arm = arm_info("thumbv6m-none-eabi");
+        assert_eq!(arm.march(), "armv6-m");
+        assert_eq!(arm.float_abi, ArmFloatAbi::Soft);
+        assert_eq!(arm.fpu, None);
+
+        let arm = arm_info("thumbv8m.main-none-eabihf");
+        assert_eq!(arm.march(), "armv8-m.main");
+        assert_eq!(arm.fpu, Some(ArmFpu::FpV5SpD16));
+        assert!(!arm.fpu.unwrap().has_double_precision());
+
+        let arm = arm_info("thumbv7em-none-eabi");
+        assert_eq!(arm.float_abi, ArmFloatAbi::Soft);
+        assert_eq!(arm.fpu, None);
+    }
+
+    #[test]
+    fn application() {
+        let arm = arm_info("armv7-linux-androideabi");
+        assert!(arm.thumb_mode);
+        assert_eq!(arm.float_abi, ArmFloatAbi::SoftFp);
+        assert_eq!(arm.fpu, Some(ArmFpu::Vfpv3D16));
+
+        let arm = arm_info("thumbv7neon-unknown-linux-gnueabihf");
+        assert!(arm.thumb_mode && !arm.thumb_only);
+        assert!(arm.has_neon());
+        assert_eq!(arm.march(), "armv7-a");
+
+        let arm = arm_info("arm-unknown-linux-gnueabihf");
+        assert_eq!(arm.sub_arch, SubArch::ArmV6);
+        assert_eq!(arm.profile, None);
+        assert_eq!(arm.fpu, Some(ArmFpu::Vfpv2));
+        assert_eq!(arm.march(), "armv6");
+
+        let arm = arm_info("armv7r-none-eabihf");
+        assert_eq!(arm.march(), "armv7-r");
+
+        let target = Target::from_triple("armv-unknown-linux-gnueabi").unwrap();
+        assert_eq!(target.arm_info().unwrap().march(), "armv");
+    }
+
+    #[test]
+    fn features() {
+        let mut target = Target::lookup("armv7-unknown-linux-gnueabihf")
+            .unwrap()
+            .clone();
+        target.features.extend([
+            TargetFeature::Vfp3,
+            TargetFeature::Vfp4,
+            TargetFeature::D32,
+            TargetFeature::Neon,
+        ]);
+        assert_eq!(target.arm_info().unwrap().fpu, Some(ArmFpu::NeonVfpv4));
+    }
+}
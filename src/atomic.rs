@@ -0,0 +1,143 @@
+use std::fmt;
+
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// Width of an atomic operation as used by `#[cfg(target_has_atomic)]`.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum AtomicWidth {
+        /// 8-bit atomics (e.g. [`AtomicU8`](std::sync::atomic::AtomicU8)).
+        U8 => "8",
+        /// 16-bit atomics (e.g. [`AtomicU16`](std::sync::atomic::AtomicU16)).
+        U16 => "16",
+        /// 32-bit atomics (e.g. [`AtomicU32`](std::sync::atomic::AtomicU32)).
+        U32 => "32",
+        /// 64-bit atomics (e.g. [`AtomicU64`](std::sync::atomic::AtomicU64)).
+        U64 => "64",
+        /// 128-bit atomics.
+        U128 => "128",
+        /// Pointer sized atomics (e.g. [`AtomicUsize`](std::sync::atomic::AtomicUsize)).
+        Ptr => "ptr",
+    }
+
+    as_str_doc = "String representing this atomic width which matches `#[cfg(target_has_atomic)]`.",
+    from_str_doc = "Tries to parse the given string as an [`AtomicWidth`] falling back to [`AtomicWidth::Other`] for unknown values.",
+}
+
+impl AtomicWidth {
+    /// Gets the number of bits of this width or [`None`] for [`AtomicWidth::Ptr`] and unknown widths.
+    #[must_use]
+    pub fn bits(&self) -> Option<u8> {
+        match self {
+            Self::U8 => Some(8),
+            Self::U16 => Some(16),
+            Self::U32 => Some(32),
+            Self::U64 => Some(64),
+            Self::U128 => Some(128),
+            Self::Ptr => None,
+            Self::Other(s) => s.parse().ok(),
+        }
+    }
+}
+
+impl fmt::Display for AtomicWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Atomic operations supported by a target.
+///
+/// # Note
+/// `target_has_atomic_load_store` and `target_has_atomic_equal_alignment` are unstable cfgs which are only
+/// exposed by nightly compilers, so [`AtomicSupport::load_store`] and [`AtomicSupport::equal_alignment`]
+/// are empty on stable.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct AtomicSupport {
+    /// Widths with full atomic support including compare-and-swap (`target_has_atomic`).
+    pub has_atomic: Vec<AtomicWidth>,
+    /// Widths with atomic loads and stores (`target_has_atomic_load_store`).
+    pub load_store: Vec<AtomicWidth>,
+    /// Widths for which the atomic types have the same alignment as the corresponding integer types
+    /// (`target_has_atomic_equal_alignment`).
+    pub equal_alignment: Vec<AtomicWidth>,
+}
+
+impl AtomicSupport {
+    /// Gets the [`AtomicSupport`] of the current target.
+    #[must_use]
+    pub fn target() -> Self {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the [`AtomicSupport`] of the current target.
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`AtomicSupport`] of the target from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        Ok(Self {
+            has_atomic: Self::widths_from_env(env, "CARGO_CFG_TARGET_HAS_ATOMIC")?,
+            load_store: Self::widths_from_env(env, "CARGO_CFG_TARGET_HAS_ATOMIC_LOAD_STORE")?,
+            equal_alignment: Self::widths_from_env(
+                env,
+                "CARGO_CFG_TARGET_HAS_ATOMIC_EQUAL_ALIGNMENT",
+            )?,
+        })
+    }
+
+    fn widths_from_env(env: &impl EnvSource, name: &str) -> Result<Vec<AtomicWidth>, Error> {
+        Ok(Self::widths_from_list(
+            &build_env_opt(env, name)?.unwrap_or_default(),
+        ))
+    }
+
+    /// Parses a comma separated list of widths such as `8,16,32,64,ptr`.
+    pub(crate) fn widths_from_list(list: &str) -> Vec<AtomicWidth> {
+        let mut widths = list
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .map(AtomicWidth::from_str)
+            .collect::<Vec<_>>();
+        widths.sort();
+        widths
+    }
+
+    /// Checks whether atomic operations including compare-and-swap are supported for the given width.
+    #[must_use]
+    pub fn has_atomic(&self, width: &AtomicWidth) -> bool {
+        self.has_atomic.contains(width)
+    }
+
+    /// Checks whether atomic loads and stores are supported for the given width.
+    ///
+    /// As full atomic support implies atomic loads and stores, this also returns `true` for the widths
+    /// in [`AtomicSupport::has_atomic`] on compilers that do not expose `target_has_atomic_load_store`.
+    #[must_use]
+    pub fn has_atomic_load_store(&self, width: &AtomicWidth) -> bool {
+        self.load_store.contains(width) || self.has_atomic(width)
+    }
+
+    /// Gets the largest width in bits for which atomic operations including compare-and-swap are supported,
+    /// or [`None`] if the target has no atomic operations at all.
+    ///
+    /// # Example
+    /// ```rust
+    /// use build_target::Target;
+    ///
+    /// let target = Target::lookup("thumbv7m-none-eabi").unwrap();
+    /// assert_eq!(target.atomics.max_atomic_width(), Some(32));
+    ///
+    /// let target = Target::lookup("riscv32imc-unknown-none-elf").unwrap();
+    /// assert_eq!(target.atomics.max_atomic_width(), None);
+    /// ```
+    #[must_use]
+    pub fn max_atomic_width(&self) -> Option<u8> {
+        self.has_atomic.iter().filter_map(AtomicWidth::bits).max()
+    }
+}
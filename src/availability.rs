@@ -0,0 +1,303 @@
+use crate::{Arch, Env, Family, Os, Vendor, Version};
+
+/// Range of rustc versions which emit a value of `target_arch`, `target_os`, `target_env`, `target_vendor` or
+/// `target_family`.
+///
+/// The versions refer to the first stable release with a built-in target using the respective value. Values
+/// which are no longer emitted by current compilers are not part of the enums but can still be queried through
+/// their `Other` variant.
+///
+/// A renamed value is modeled as the removal of the old value, whose [`Availability::replaced_by`] names the new
+/// value, and the addition of the new value with an availability of its own. Currently the only tracked rename is
+/// that of `target_arch = "asmjs"` to `"wasm32"`.
+///
+/// Values without availability data, e.g. values added by compilers newer than this crate or passed through
+/// custom targets, are reported as unknown rather than as available, so a build script can fail with a precise
+/// error instead of silently accepting them.
+///
+/// # Example
+/// ```rust
+/// use build_target::{Arch, Os, Version};
+///
+/// assert_eq!(Os::Cygwin.available_since(), Some(Version::new(1, 88, 0)));
+/// assert_eq!(Os::Other("cloudabi".to_string()).is_available_in(Version::new(1, 85, 0)), Some(false));
+/// assert_eq!(Os::Other("my-os".to_string()).is_available_in(Version::new(1, 85, 0)), None);
+///
+/// let asmjs = Arch::Other("asmjs".to_string()).availability().unwrap();
+/// assert_eq!(asmjs.replaced_by, Some("wasm32"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Availability {
+    /// The first version emitting the value.
+    pub since: Version,
+    /// The first version no longer emitting the value, if it has been removed.
+    pub removed_in: Option<Version>,
+    /// The value emitted instead since the value was removed, if it has been renamed or merged into another one.
+    pub replaced_by: Option<&'static str>,
+}
+
+impl Availability {
+    /// Checks whether the value is emitted by the given rustc version.
+    #[must_use]
+    pub fn is_available_in(&self, version: Version) -> bool {
+        self.since <= version
+            && self
+                .removed_in
+                .is_none_or(|removed_in| version < removed_in)
+    }
+}
+
+macro_rules! define_availability {
+    (@removed) => { None };
+    (@removed $major:literal, $minor:literal) => { Some(Version::new($major, $minor, 0)) };
+    (@replaced) => { None };
+    (@replaced $replacement:literal) => { Some($replacement) };
+    (
+        $name:ident {
+            $($value:literal => ($since_major:literal, $since_minor:literal) $(.. ($removed_major:literal, $removed_minor:literal) $(=> $replacement:literal)?)?),+ $(,)?
+        }
+    ) => {
+        impl $name {
+            #[doc = concat!("Gets the range of rustc versions which emit this [`", stringify!($name), "`], or [`None`] if the value is unknown.")]
+            #[must_use]
+            pub fn availability(&self) -> Option<Availability> {
+                match self.as_str() {
+                    $($value => Some(Availability {
+                        since: Version::new($since_major, $since_minor, 0),
+                        removed_in: define_availability!(@removed $($removed_major, $removed_minor)?),
+                        replaced_by: define_availability!(@replaced $($($replacement)?)?),
+                    }),)+
+                    _ => None,
+                }
+            }
+
+            #[doc = concat!("Gets the first rustc version which emits this [`", stringify!($name), "`], or [`None`] if the value is unknown.")]
+            #[must_use]
+            pub fn available_since(&self) -> Option<Version> {
+                self.availability().map(|availability| availability.since)
+            }
+
+            #[doc = concat!("Checks whether this [`", stringify!($name), "`] is emitted by the given rustc version, or [`None`] if the value is unknown.")]
+            #[must_use]
+            pub fn is_available_in(&self, version: Version) -> Option<bool> {
+                self.availability()
+                    .map(|availability| availability.is_available_in(version))
+            }
+        }
+    };
+}
+
+define_availability!(Arch {
+    "aarch64" => (1, 0),
+    "amdgpu" => (1, 86),
+    "arm" => (1, 0),
+    "arm64ec" => (1, 78),
+    "asmjs" => (1, 11)..(1, 76) => "wasm32",
+    "avr" => (1, 48),
+    "bpf" => (1, 54),
+    "csky" => (1, 72),
+    "hexagon" => (1, 38),
+    "loongarch32" => (1, 89),
+    "loongarch64" => (1, 71),
+    "m68k" => (1, 54),
+    "mips" => (1, 0),
+    "mips32r6" => (1, 73),
+    "mips64" => (1, 11),
+    "mips64r6" => (1, 73),
+    "msp430" => (1, 26),
+    "nvptx64" => (1, 29),
+    "powerpc" => (1, 0),
+    "powerpc64" => (1, 8),
+    "riscv32" => (1, 28),
+    "riscv64" => (1, 35),
+    "s390x" => (1, 12),
+    "sparc" => (1, 33),
+    "sparc64" => (1, 14),
+    "wasm32" => (1, 14),
+    "wasm64" => (1, 56),
+    "x86" => (1, 0),
+    "x86_64" => (1, 0),
+    "xtensa" => (1, 76),
+});
+
+define_availability!(Os {
+    "aix" => (1, 76),
+    "amdhsa" => (1, 86),
+    "android" => (1, 0),
+    "bitrig" => (1, 0)..(1, 29),
+    "cloudabi" => (1, 23)..(1, 51),
+    "cuda" => (1, 29),
+    "cygwin" => (1, 88),
+    "dragonfly" => (1, 0),
+    "emscripten" => (1, 11),
+    "espidf" => (1, 56),
+    "freebsd" => (1, 0),
+    "fuchsia" => (1, 17),
+    "haiku" => (1, 13),
+    "helenos" => (1, 91),
+    "hermit" => (1, 36),
+    "horizon" => (1, 63),
+    "hurd" => (1, 74),
+    "illumos" => (1, 46),
+    "ios" => (1, 0),
+    "l4re" => (1, 24),
+    "linux" => (1, 0),
+    "lynxos178" => (1, 87),
+    "macos" => (1, 0),
+    "managarm" => (1, 91),
+    "motor" => (1, 93),
+    "netbsd" => (1, 3),
+    "none" => (1, 19),
+    "nto" => (1, 67),
+    "nuttx" => (1, 84),
+    "openbsd" => (1, 0),
+    "psp" => (1, 41),
+    "psx" => (1, 66),
+    "qurt" => (1, 92),
+    "redox" => (1, 19),
+    "rtems" => (1, 84),
+    "solaris" => (1, 5),
+    "solid_asp3" => (1, 57),
+    "teeos" => (1, 75),
+    "trusty" => (1, 85),
+    "tvos" => (1, 42),
+    "uefi" => (1, 34),
+    "unknown" => (1, 22),
+    "vexos" => (1, 89),
+    "visionos" => (1, 78),
+    "vita" => (1, 67),
+    "vxworks" => (1, 37),
+    "wasi" => (1, 33),
+    "watchos" => (1, 61),
+    "windows" => (1, 0),
+    "xous" => (1, 70),
+    "zkvm" => (1, 75),
+});
+
+define_availability!(Env {
+    "gnu" => (1, 0),
+    "macabi" => (1, 88),
+    "mlibc" => (1, 91),
+    "msvc" => (1, 2),
+    "musl" => (1, 1),
+    "newlib" => (1, 56),
+    "nto70" => (1, 67),
+    "nto71" => (1, 67),
+    "nto71_iosock" => (1, 86),
+    "nto80" => (1, 87),
+    "ohos" => (1, 71),
+    "p1" => (1, 78),
+    "p2" => (1, 78),
+    "p3" => (1, 91),
+    "relibc" => (1, 33),
+    "sgx" => (1, 33),
+    "sim" => (1, 88),
+    "uclibc" => (1, 24),
+    "v5" => (1, 89),
+});
+
+define_availability!(Vendor {
+    "amd" => (1, 86),
+    "apple" => (1, 0),
+    "espressif" => (1, 56),
+    "fortanix" => (1, 33),
+    "ibm" => (1, 76),
+    "kmc" => (1, 57),
+    "mti" => (1, 84),
+    "nintendo" => (1, 63),
+    "nvidia" => (1, 29),
+    "openwrt" => (1, 79),
+    "pc" => (1, 0),
+    "risc0" => (1, 75),
+    "rumprun" => (1, 6)..(1, 53),
+    "sony" => (1, 41),
+    "sun" => (1, 5),
+    "unikraft" => (1, 76),
+    "unknown" => (1, 0),
+    "uwp" => (1, 43),
+    "vex" => (1, 89),
+    "win7" => (1, 78),
+    "wrs" => (1, 37),
+});
+
+define_availability!(Family {
+    "unix" => (1, 0),
+    "wasm" => (1, 54),
+    "windows" => (1, 0),
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_known_values_have_availability() {
+        assert!(Arch::all().all(|value| value.availability().is_some()));
+        assert!(Os::all().all(|value| value.availability().is_some()));
+        assert!(Env::all().all(|value| value.availability().is_some()));
+        assert!(Vendor::all().all(|value| value.availability().is_some()));
+        assert!(Family::all().all(|value| value.availability().is_some()));
+    }
+
+    #[test]
+    fn known_values_are_available_today() {
+        let version = Version::new(1, 95, 0);
+        assert!(Arch::all().all(|value| value.is_available_in(version) == Some(true)));
+        assert!(Os::all().all(|value| value.is_available_in(version) == Some(true)));
+        assert!(Env::all().all(|value| value.is_available_in(version) == Some(true)));
+        assert!(Vendor::all().all(|value| value.is_available_in(version) == Some(true)));
+        assert!(Family::all().all(|value| value.is_available_in(version) == Some(true)));
+    }
+
+    #[test]
+    fn removed_values() {
+        let cloudabi = Os::Other("cloudabi".to_string());
+        assert_eq!(
+            cloudabi.availability(),
+            Some(Availability {
+                since: Version::new(1, 23, 0),
+                removed_in: Some(Version::new(1, 51, 0)),
+                replaced_by: None,
+            })
+        );
+        assert_eq!(cloudabi.is_available_in(Version::new(1, 50, 0)), Some(true));
+        assert_eq!(
+            cloudabi.is_available_in(Version::new(1, 51, 0)),
+            Some(false)
+        );
+        assert_eq!(
+            Os::Cygwin.is_available_in(Version::new(1, 87, 0)),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn unknown_values() {
+        let version = Version::new(1, 95, 0);
+        assert_eq!(Os::Other("my-os".to_string()).availability(), None);
+        assert_eq!(
+            Os::Other("my-os".to_string()).is_available_in(version),
+            None
+        );
+        assert_eq!(
+            Arch::Other("my-arch".to_string()).is_available_in(version),
+            None
+        );
+    }
+
+    #[test]
+    fn renamed_values() {
+        let asmjs = Arch::Other("asmjs".to_string()).availability().unwrap();
+        assert_eq!(asmjs.removed_in, Some(Version::new(1, 76, 0)));
+        assert_eq!(asmjs.replaced_by, Some("wasm32"));
+
+        // the replacement is a value of its own which was available before the rename
+        let wasm32 = Arch::from_str(asmjs.replaced_by.unwrap());
+        assert_eq!(wasm32, Arch::Wasm32);
+        assert_eq!(
+            wasm32.is_available_in(asmjs.removed_in.unwrap()),
+            Some(true)
+        );
+        assert_eq!(Arch::Wasm32.availability().unwrap().replaced_by, None);
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env, build_env_flag, define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// Optimization level of the current build as set by the `opt-level` profile setting.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum OptLevel {
+        /// No optimizations.
+        O0 => "0",
+        /// Basic optimizations.
+        O1 => "1",
+        /// Some optimizations.
+        O2 => "2",
+        /// All optimizations.
+        O3 => "3",
+        /// Optimize for binary size.
+        Size => "s",
+        /// Optimize for binary size, but also turn off loop vectorization.
+        SizeMin => "z",
+    }
+
+    as_str_doc = "String representing this optimization level which matches the `opt-level` profile setting.",
+    from_str_doc = "Tries to parse the given string as an [`OptLevel`] falling back to [`OptLevel::Other`] for unknown values.",
+}
+
+impl OptLevel {
+    /// Gets the current [`OptLevel`].
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the current [`OptLevel`].
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`OptLevel`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "OPT_LEVEL").map(Self::from_str)
+    }
+
+    /// Checks whether this level enables any optimizations.
+    #[must_use]
+    pub fn is_optimized(&self) -> bool {
+        *self != Self::O0
+    }
+}
+
+impl fmt::Display for OptLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+define_target_enum! {
+    /// Amount of debug information of the current build as set by the `debug` profile setting.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum DebugInfo {
+        /// No debug info at all (also `0` or `false`).
+        None => "none",
+        /// Line directives only, without any other debug info.
+        LineDirectivesOnly => "line-directives-only",
+        /// Line tables only, without any information about variables or types.
+        LineTablesOnly => "line-tables-only",
+        /// Debug info without type or variable-level information (also `1`).
+        Limited => "limited",
+        /// Full debug info (also `2` or `true`).
+        Full => "full",
+    }
+
+    as_str_doc = "String representing this debug info level which matches the `debug` profile setting.",
+    from_str_doc = "Tries to parse the given string as a [`DebugInfo`] falling back to [`DebugInfo::Other`] for unknown values.",
+}
+
+impl DebugInfo {
+    /// Gets the current [`DebugInfo`].
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the current [`DebugInfo`].
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`DebugInfo`] from the given [`EnvSource`].
+    ///
+    /// # Note
+    /// Cargo only sets `DEBUG` to `true` or `false`, so the exact level is not available to build scripts. As
+    /// with `debug = true` in a profile, `true` is reported as [`DebugInfo::Full`] even if a lower level is
+    /// configured; use [`DebugInfo::is_enabled()`] to check for debug info in build scripts. The numeric levels
+    /// `0`, `1` and `2` are mapped to their named equivalents for other sources.
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "DEBUG").map(|value| match value.as_str() {
+            "0" | "false" => Self::None,
+            "1" => Self::Limited,
+            "2" | "true" => Self::Full,
+            value => Self::from_str(value),
+        })
+    }
+
+    /// Checks whether any debug info is generated.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        *self != Self::None
+    }
+}
+
+impl fmt::Display for DebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+define_target_enum! {
+    /// Strategy used when a panic occurs, which matches `#[cfg(panic)]`.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum PanicStrategy {
+        /// Unwind the stack on panic.
+        Unwind => "unwind",
+        /// Abort the process on panic.
+        Abort => "abort",
+    }
+
+    as_str_doc = "String representing this panic strategy which matches `#[cfg(panic)]`.",
+    from_str_doc = "Tries to parse the given string as a [`PanicStrategy`] falling back to [`PanicStrategy::Other`] for unknown values.",
+}
+
+impl PanicStrategy {
+    /// Gets the current [`PanicStrategy`].
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the current [`PanicStrategy`].
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`PanicStrategy`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "CARGO_CFG_PANIC").map(Self::from_str)
+    }
+}
+
+impl fmt::Display for PanicStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Code generation settings of the current build, which are defined by the active [`Profile`](crate::Profile).
+///
+/// # Note
+/// `overflow_checks` is an unstable cfg which is only exposed by nightly compilers, so
+/// [`BuildConfig::overflow_checks`] is always `false` on stable.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct BuildConfig {
+    /// The optimization level, such as `0`, `3` or `s`.
+    pub opt_level: OptLevel,
+    /// The amount of debug information, such as `none`, `limited` or `full`.
+    pub debug_info: DebugInfo,
+    /// Whether debug assertions are enabled (`#[cfg(debug_assertions)]`).
+    pub debug_assertions: bool,
+    /// Whether integer overflow checks are enabled (`#[cfg(overflow_checks)]`).
+    pub overflow_checks: bool,
+    /// The panic strategy, such as `unwind` or `abort`.
+    pub panic: PanicStrategy,
+}
+
+impl BuildConfig {
+    /// Gets the [`BuildConfig`] of the current build.
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the [`BuildConfig`] of the current build.
+    ///
+    /// If multiple variables are missing, all of them are reported in a single [`Error::MissingVariables`].
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`BuildConfig`] of the build described by the given [`EnvSource`].
+    ///
+    /// If multiple variables are missing, all of them are reported in a single [`Error::MissingVariables`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        let opt_level = OptLevel::from_env(env);
+        let debug_info = DebugInfo::from_env(env);
+        let panic = PanicStrategy::from_env(env);
+
+        let error = [
+            opt_level.as_ref().err(),
+            debug_info.as_ref().err(),
+            panic.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .reduce(Error::merge);
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(Self {
+            opt_level: opt_level?,
+            debug_info: debug_info?,
+            debug_assertions: build_env_flag(env, "CARGO_CFG_DEBUG_ASSERTIONS"),
+            overflow_checks: build_env_flag(env, "CARGO_CFG_OVERFLOW_CHECKS"),
+            panic: panic?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn dev() {
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("OPT_LEVEL", "0"),
+            ("DEBUG", "true"),
+            ("CARGO_CFG_DEBUG_ASSERTIONS", ""),
+            ("CARGO_CFG_OVERFLOW_CHECKS", ""),
+            ("CARGO_CFG_PANIC", "unwind"),
+        ]);
+        assert_eq!(
+            BuildConfig::from_env(&env),
+            Ok(BuildConfig {
+                opt_level: OptLevel::O0,
+                debug_info: DebugInfo::Full,
+                debug_assertions: true,
+                overflow_checks: true,
+                panic: PanicStrategy::Unwind,
+            })
+        );
+    }
+
+    #[test]
+    fn release() {
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("OPT_LEVEL", "z"),
+            ("DEBUG", "false"),
+            ("CARGO_CFG_PANIC", "abort"),
+        ]);
+        assert_eq!(
+            BuildConfig::from_env(&env),
+            Ok(BuildConfig {
+                opt_level: OptLevel::SizeMin,
+                debug_info: DebugInfo::None,
+                debug_assertions: false,
+                overflow_checks: false,
+                panic: PanicStrategy::Abort,
+            })
+        );
+    }
+
+    #[test]
+    fn debug_values() {
+        for (value, expected) in [
+            ("true", DebugInfo::Full),
+            ("false", DebugInfo::None),
+            ("0", DebugInfo::None),
+            ("1", DebugInfo::Limited),
+            ("2", DebugInfo::Full),
+        ] {
+            let env = HashMap::from([("TARGET", "x86_64-unknown-linux-gnu"), ("DEBUG", value)]);
+            assert_eq!(DebugInfo::from_env(&env), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn missing_variables() {
+        let env = HashMap::from([("TARGET", "x86_64-unknown-linux-gnu")]);
+        assert_eq!(
+            BuildConfig::from_env(&env),
+            Err(Error::MissingVariables(vec![
+                "OPT_LEVEL".to_string(),
+                "DEBUG".to_string(),
+                "CARGO_CFG_PANIC".to_string(),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::{path::PathBuf, sync::OnceLock};
+
+use crate::{
+    EnvSource, Error, Features, ProcessEnv, Profile, RustFlags, Target,
+    utils::{build_env, build_env_opt, expect_env},
+};
+
+/// Metadata of the package whose build script is running, as defined in its `Cargo.toml`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Package {
+    /// The name of the package (`CARGO_PKG_NAME`).
+    pub name: String,
+    /// The full version of the package (`CARGO_PKG_VERSION`).
+    pub version: String,
+    /// The authors of the package (`CARGO_PKG_AUTHORS`).
+    pub authors: Vec<String>,
+    /// The description of the package (`CARGO_PKG_DESCRIPTION`).
+    pub description: Option<String>,
+    /// The homepage of the package (`CARGO_PKG_HOMEPAGE`).
+    pub homepage: Option<String>,
+    /// The repository of the package (`CARGO_PKG_REPOSITORY`).
+    pub repository: Option<String>,
+    /// The license of the package (`CARGO_PKG_LICENSE`).
+    pub license: Option<String>,
+    /// The minimum supported Rust version of the package (`CARGO_PKG_RUST_VERSION`).
+    pub rust_version: Option<String>,
+    /// The name of the native library the package links to (`CARGO_MANIFEST_LINKS`).
+    pub links: Option<String>,
+}
+
+impl Package {
+    /// Gets the [`Package`] of the build described by the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        let name = build_env(env, "CARGO_PKG_NAME");
+        let version = build_env(env, "CARGO_PKG_VERSION");
+        let error = [name.as_ref().err(), version.as_ref().err()]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .reduce(Error::merge);
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(Self {
+            name: name?,
+            version: version?,
+            authors: build_env_opt(env, "CARGO_PKG_AUTHORS")?
+                .map(|authors| authors.split(':').map(str::to_string).collect())
+                .unwrap_or_default(),
+            description: build_env_opt(env, "CARGO_PKG_DESCRIPTION")?,
+            homepage: build_env_opt(env, "CARGO_PKG_HOMEPAGE")?,
+            repository: build_env_opt(env, "CARGO_PKG_REPOSITORY")?,
+            license: build_env_opt(env, "CARGO_PKG_LICENSE")?,
+            rust_version: build_env_opt(env, "CARGO_PKG_RUST_VERSION")?,
+            links: build_env_opt(env, "CARGO_MANIFEST_LINKS")?,
+        })
+    }
+}
+
+/// The whole environment of a build script, i.e. everything Cargo tells it about the current build.
+///
+/// # Example
+/// ```rust no_run
+/// // inside build.rs
+///
+/// fn main() {
+///     let ctx = build_target::BuildContext::current();
+///     if ctx.target.os == build_target::Os::Windows {
+///         println!("cargo:warning=building {} for windows into {}", ctx.package.name, ctx.out_dir.display());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildContext {
+    /// The target the package is built for.
+    pub target: Target,
+    /// The host the build script and compiler run on.
+    pub host: Target,
+    /// The profile of the build. See [`Profile::current()`] for details.
+    pub profile: Profile,
+    /// The directory in which all output and intermediate artifacts should be placed (`OUT_DIR`).
+    pub out_dir: PathBuf,
+    /// The directory containing the manifest of the package (`CARGO_MANIFEST_DIR`).
+    pub manifest_dir: PathBuf,
+    /// The parallelism specified as the top-level parallelism (`NUM_JOBS`).
+    pub num_jobs: usize,
+    /// The enabled features of the package.
+    pub features: Features,
+    /// The compiler used for the build (`RUSTC`).
+    pub rustc: PathBuf,
+    /// The wrapper rustc is invoked through, if any (`RUSTC_WRAPPER`).
+    pub rustc_wrapper: Option<PathBuf>,
+    /// The linker passed to rustc, if any (`RUSTC_LINKER`).
+    pub rustc_linker: Option<PathBuf>,
+    /// The flags passed to rustc (`CARGO_ENCODED_RUSTFLAGS`).
+    pub rustflags: RustFlags,
+    /// The metadata of the package.
+    pub package: Package,
+}
+
+impl BuildContext {
+    /// Gets the [`BuildContext`] of the current build.
+    ///
+    /// The environment is only read on the first call, later calls return the same instance.
+    #[must_use]
+    pub fn current() -> &'static Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the [`BuildContext`] of the current build. See [`BuildContext::current()`] for details.
+    ///
+    /// If multiple variables are missing, all of them are reported in a single [`Error::MissingVariables`].
+    pub fn try_current() -> Result<&'static Self, Error> {
+        static CURRENT: OnceLock<Result<BuildContext, Error>> = OnceLock::new();
+        CURRENT
+            .get_or_init(|| Self::from_env(&ProcessEnv))
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Gets the [`BuildContext`] of the build described by the given [`EnvSource`].
+    ///
+    /// In contrast to [`BuildContext::current()`], the result is not cached.
+    /// If multiple variables are missing, all of them are reported in a single [`Error::MissingVariables`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        let target = Target::from_env(env);
+        let host = Target::host_from_env(env);
+        let profile = Profile::from_env(env);
+        let out_dir = build_env(env, "OUT_DIR");
+        let manifest_dir = build_env(env, "CARGO_MANIFEST_DIR");
+        let num_jobs = build_env(env, "NUM_JOBS").and_then(|value| {
+            value.parse().map_err(|_| Error::InvalidValue {
+                name: "NUM_JOBS".to_string(),
+                value,
+            })
+        });
+        let features = Features::from_env(env);
+        let rustc = build_env(env, "RUSTC");
+        let rustflags = RustFlags::from_env(env);
+        let package = Package::from_env(env);
+
+        let error = [
+            target.as_ref().err(),
+            host.as_ref().err(),
+            profile.as_ref().err(),
+            out_dir.as_ref().err(),
+            manifest_dir.as_ref().err(),
+            num_jobs.as_ref().err(),
+            features.as_ref().err(),
+            rustc.as_ref().err(),
+            rustflags.as_ref().err(),
+            package.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .reduce(Error::merge);
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(Self {
+            target: target?,
+            host: host?,
+            profile: profile?,
+            out_dir: out_dir?.into(),
+            manifest_dir: manifest_dir?.into(),
+            num_jobs: num_jobs?,
+            features: features?,
+            rustc: rustc?.into(),
+            rustc_wrapper: build_env_opt(env, "RUSTC_WRAPPER")?.map(PathBuf::from),
+            rustc_linker: build_env_opt(env, "RUSTC_LINKER")?.map(PathBuf::from),
+            rustflags: rustflags?,
+            package: package?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EnvOverride, Os};
+    use std::collections::HashMap;
+
+    fn linux() -> HashMap<&'static str, &'static str> {
+        HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("HOST", "x86_64-unknown-linux-gnu"),
+            ("CARGO_CFG_TARGET_ARCH", "x86_64"),
+            ("CARGO_CFG_TARGET_ENDIAN", "little"),
+            ("CARGO_CFG_TARGET_ENV", "gnu"),
+            ("CARGO_CFG_TARGET_OS", "linux"),
+            ("CARGO_CFG_TARGET_POINTER_WIDTH", "64"),
+            ("CARGO_CFG_TARGET_FAMILY", "unix"),
+            ("CARGO_CFG_TARGET_VENDOR", "unknown"),
+            ("CARGO_CFG_TARGET_HAS_ATOMIC", "8,16,32,64,ptr"),
+            ("PROFILE", "debug"),
+            (
+                "OUT_DIR",
+                "/work/target/debug/build/foo-0123456789abcdef/out",
+            ),
+            ("CARGO_MANIFEST_DIR", "/work/foo"),
+            ("NUM_JOBS", "8"),
+            ("RUSTC", "rustc"),
+            ("CARGO_PKG_NAME", "foo"),
+            ("CARGO_PKG_VERSION", "1.2.3"),
+            ("CARGO_PKG_AUTHORS", "Alice:Bob"),
+            ("CARGO_PKG_DESCRIPTION", ""),
+            ("CARGO_FEATURE_DEFAULT", "1"),
+            ("CARGO_FEATURE_SERDE_JSON", "1"),
+        ])
+    }
+
+    #[test]
+    fn from_env() {
+        let ctx = BuildContext::from_env(&linux()).unwrap();
+        assert_eq!(ctx.target.os, Os::Linux);
+        assert_eq!(Some(&ctx.host), Target::lookup("x86_64-unknown-linux-gnu"));
+        assert_eq!(ctx.profile, Profile::Dev);
+        assert_eq!(ctx.manifest_dir, PathBuf::from("/work/foo"));
+        assert_eq!(ctx.num_jobs, 8);
+        assert_eq!(
+            ctx.features.iter().collect::<Vec<_>>(),
+            ["default", "serde_json"]
+        );
+        assert_eq!(ctx.rustc_wrapper, None);
+        assert_eq!(ctx.package.name, "foo");
+        assert_eq!(ctx.package.authors, ["Alice", "Bob"]);
+        assert_eq!(ctx.package.description, None);
+    }
+
+    #[test]
+    fn invalid_num_jobs() {
+        let env = EnvOverride::new(linux()).set("NUM_JOBS", "many");
+        assert_eq!(
+            BuildContext::from_env(&env),
+            Err(Error::InvalidValue {
+                name: "NUM_JOBS".to_string(),
+                value: "many".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn reports_all_missing_variables() {
+        let env = EnvOverride::new(linux())
+            .remove("OUT_DIR")
+            .remove("CARGO_PKG_NAME");
+        assert_eq!(
+            BuildContext::from_env(&env),
+            Err(Error::MissingVariables(vec![
+                "OUT_DIR".to_string(),
+                "CARGO_PKG_NAME".to_string()
+            ]))
+        );
+    }
+}
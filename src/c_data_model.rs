@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::{
+    Arch, Env, EnvSource, Error, Os, PointerWidth, ProcessEnv, Target, Vendor,
+    utils::{define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// Data model of the C ABI of a target, i.e. the widths of `int`, `long` and pointers.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum DataModel {
+        /// 16-bit `int` and pointers as used by AVR and MSP430 (`long` is still 32 bits)
+        Ilp16 => "ilp16",
+        /// 32-bit `int`, `long` and pointers as used by all other 32-bit targets
+        Ilp32 => "ilp32",
+        /// 32-bit `int` with 64-bit `long` and pointers as used by Unix-like 64-bit targets
+        Lp64 => "lp64",
+        /// 32-bit `int` and `long` with 64-bit pointers as used by 64-bit Windows and UEFI
+        Llp64 => "llp64",
+    }
+
+    as_str_doc = "String representing this data model.",
+    from_str_doc = "Tries to parse the given string as a [`DataModel`] falling back to [`DataModel::Other`] for unknown values.",
+}
+
+impl fmt::Display for DataModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Size and alignment of a C type in bytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct CTypeLayout {
+    /// The size of the type in bytes, i.e. `sizeof(T)`.
+    pub size: u8,
+    /// The alignment of the type in bytes, i.e. `_Alignof(T)`.
+    pub align: u8,
+}
+
+impl CTypeLayout {
+    /// Creates a new [`CTypeLayout`] from its size and alignment in bytes.
+    #[must_use]
+    pub const fn new(size: u8, align: u8) -> Self {
+        Self { size, align }
+    }
+
+    /// Gets the size of the type in bits.
+    #[must_use]
+    pub const fn bits(&self) -> u16 {
+        self.size as u16 * 8
+    }
+}
+
+/// Sizes, alignments and signedness of the C types of a target as used by its default C compiler.
+///
+/// # Example
+/// ```rust
+/// use build_target::{CTypeLayout, DataModel, Target};
+///
+/// let model = Target::lookup("x86_64-pc-windows-msvc").unwrap().c_data_model();
+/// assert_eq!(model.model, DataModel::Llp64);
+/// assert_eq!(model.long, CTypeLayout::new(4, 4));
+/// assert_eq!(model.wchar, CTypeLayout::new(2, 2));
+///
+/// let model = Target::lookup("avr-none").unwrap().c_data_model();
+/// assert_eq!(model.model, DataModel::Ilp16);
+/// assert_eq!(model.int, CTypeLayout::new(2, 1));
+///
+/// let model = Target::lookup("aarch64-unknown-linux-gnu").unwrap().c_data_model();
+/// assert!(!model.char_signed);
+/// assert_eq!(model.long_double, CTypeLayout::new(16, 16));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CDataModel {
+    /// The data model, i.e. the widths of `int`, `long` and pointers.
+    pub model: DataModel,
+    /// The layout of `char`.
+    pub char: CTypeLayout,
+    /// Whether a plain `char` is signed.
+    pub char_signed: bool,
+    /// The layout of `short`.
+    pub short: CTypeLayout,
+    /// The layout of `int`.
+    pub int: CTypeLayout,
+    /// The layout of `long`.
+    pub long: CTypeLayout,
+    /// The layout of `long long`.
+    pub long_long: CTypeLayout,
+    /// The layout of `wchar_t`.
+    pub wchar: CTypeLayout,
+    /// Whether `wchar_t` is signed.
+    pub wchar_signed: bool,
+    /// The layout of `long double`.
+    pub long_double: CTypeLayout,
+    /// The layout of `size_t`.
+    pub size_t: CTypeLayout,
+    /// The layout of data pointers.
+    pub pointer: CTypeLayout,
+}
+
+impl CDataModel {
+    /// Gets the [`CDataModel`] of the current target.
+    #[must_use]
+    pub fn target() -> Self {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the [`CDataModel`] of the current target.
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`CDataModel`] of the target described by the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        Target::from_env(env).map(|target| Self::from_target(&target))
+    }
+
+    /// Gets the [`CDataModel`] of the given target.
+    #[must_use]
+    pub fn from_target(target: &Target) -> Self {
+        let arch = &target.arch;
+        let is_windows = matches!(target.os, Os::Windows | Os::Uefi);
+        let is_msvc = target.env == Some(Env::Msvc) || target.os == Os::Uefi;
+        let is_apple = target.vendor == Vendor::Apple;
+
+        // the largest alignment of any scalar type
+        let max_align = match arch {
+            Arch::Avr => 1,
+            Arch::Msp430 | Arch::M68k => 2,
+            _ => 16,
+        };
+        let layout = |size: u8| CTypeLayout::new(size, size.min(max_align));
+
+        let pointer = match target.pointer_width {
+            PointerWidth::U16 => layout(2),
+            PointerWidth::U32 => layout(4),
+            _ => layout(8),
+        };
+        let int = if target.pointer_width == PointerWidth::U16 {
+            layout(2)
+        } else {
+            layout(4)
+        };
+        let long = if pointer.size == 8 && !is_windows {
+            layout(8)
+        } else {
+            layout(4)
+        };
+        let long_long = match arch {
+            // the i386 System V ABI only aligns 64-bit integers to 4 bytes
+            Arch::X86 if !is_windows => CTypeLayout::new(8, 4),
+            _ => layout(8),
+        };
+
+        let long_double = match arch {
+            Arch::X86 | Arch::X86_64 if is_msvc => layout(8),
+            Arch::X86 if is_apple => layout(16),
+            Arch::X86 if target.os == Os::Android => CTypeLayout::new(8, 4),
+            Arch::X86 => CTypeLayout::new(12, 4),
+            Arch::AArch64 if is_apple || is_windows => layout(8),
+            Arch::PowerPc | Arch::PowerPc64
+                if target.os == Os::Aix
+                    || target.os == Os::FreeBSD
+                    || target.env == Some(Env::Musl) =>
+            {
+                layout(8)
+            }
+            Arch::S390X | Arch::Sparc => CTypeLayout::new(16, 8),
+            Arch::X86_64
+            | Arch::AArch64
+            | Arch::PowerPc
+            | Arch::PowerPc64
+            | Arch::Mips64
+            | Arch::Mips64r6
+            | Arch::Riscv32
+            | Arch::Riscv64
+            | Arch::Loongarch32
+            | Arch::Loongarch64
+            | Arch::Sparc64
+            | Arch::Wasm32
+            | Arch::Wasm64 => layout(16),
+            Arch::M68k => layout(12),
+            Arch::Avr => layout(4),
+            _ => layout(8),
+        };
+
+        let (wchar, wchar_signed) = match arch {
+            _ if is_windows || target.os == Os::Cygwin => (layout(2), false),
+            Arch::Avr => (layout(2), true),
+            Arch::AArch64 | Arch::Arm if !is_apple => (layout(4), false),
+            _ => (layout(4), true),
+        };
+        let char_signed = match arch {
+            Arch::AArch64 | Arch::Arm => is_apple || is_windows,
+            Arch::PowerPc
+            | Arch::PowerPc64
+            | Arch::S390X
+            | Arch::Riscv32
+            | Arch::Riscv64
+            | Arch::Csky
+            | Arch::Xtensa => false,
+            _ => true,
+        };
+
+        let model = match (int.size, long.size, pointer.size) {
+            (2, _, _) => DataModel::Ilp16,
+            (_, 8, 8) => DataModel::Lp64,
+            (_, _, 8) => DataModel::Llp64,
+            _ => DataModel::Ilp32,
+        };
+
+        Self {
+            model,
+            char: layout(1),
+            char_signed,
+            short: layout(2),
+            int,
+            long,
+            long_long,
+            wchar,
+            wchar_signed,
+            long_double,
+            size_t: pointer,
+            pointer,
+        }
+    }
+}
+
+impl Target {
+    /// Gets the [`CDataModel`] of this target. See [`CDataModel::from_target()`].
+    #[must_use]
+    pub fn c_data_model(&self) -> CDataModel {
+        CDataModel::from_target(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c_data_model(triple: &str) -> CDataModel {
+        Target::lookup(triple).unwrap().c_data_model()
+    }
+
+    #[test]
+    fn data_models() {
+        assert_eq!(
+            c_data_model("x86_64-unknown-linux-gnu").model,
+            DataModel::Lp64
+        );
+        assert_eq!(
+            c_data_model("x86_64-unknown-linux-gnux32").model,
+            DataModel::Ilp32
+        );
+        assert_eq!(
+            c_data_model("aarch64-pc-windows-gnullvm").model,
+            DataModel::Llp64
+        );
+        assert_eq!(c_data_model("x86_64-unknown-uefi").model, DataModel::Llp64);
+        assert_eq!(
+            c_data_model("arm64_32-apple-watchos").model,
+            DataModel::Ilp32
+        );
+        assert_eq!(c_data_model("msp430-none-elf").model, DataModel::Ilp16);
+
+        for target in crate::targets::all() {
+            let model = target.c_data_model();
+            assert_eq!(
+                model.pointer.bits().to_string(),
+                target.pointer_width.as_str(),
+                "{}",
+                target.triple
+            );
+            assert!(model.long.size >= model.int.size, "{}", target.triple);
+        }
+    }
+
+    #[test]
+    fn char_signedness() {
+        assert!(!c_data_model("s390x-unknown-linux-gnu").char_signed);
+        assert!(!c_data_model("riscv64gc-unknown-linux-gnu").char_signed);
+        assert!(!c_data_model("armv7-unknown-linux-gnueabihf").char_signed);
+        assert!(c_data_model("aarch64-apple-darwin").char_signed);
+        assert!(c_data_model("aarch64-pc-windows-msvc").char_signed);
+        assert!(c_data_model("x86_64-unknown-linux-gnu").char_signed);
+    }
+
+    #[test]
+    fn layouts() {
+        let model = c_data_model("i686-unknown-linux-gnu");
+        assert_eq!(model.long_long, CTypeLayout::new(8, 4));
+        assert_eq!(model.long_double, CTypeLayout::new(12, 4));
+
+        let model = c_data_model("i686-pc-windows-msvc");
+        assert_eq!(model.long_long, CTypeLayout::new(8, 8));
+        assert_eq!(model.long_double, CTypeLayout::new(8, 8));
+
+        let model = c_data_model("msp430-none-elf");
+        assert_eq!(model.long, CTypeLayout::new(4, 2));
+        assert_eq!(model.size_t, CTypeLayout::new(2, 2));
+
+        let model = c_data_model("s390x-unknown-linux-gnu");
+        assert_eq!(model.long_double, CTypeLayout::new(16, 8));
+
+        let model = c_data_model("aarch64-apple-darwin");
+        assert_eq!(model.long_double, CTypeLayout::new(8, 8));
+        assert!(model.wchar_signed);
+    }
+}
@@ -0,0 +1,372 @@
+use std::{error, fmt, iter::Peekable, str::CharIndices, str::FromStr};
+
+use crate::{AtomicWidth, Family, Target, TargetFeature};
+
+/// A `cfg` expression such as `all(unix, target_arch = "x86_64")`, as used by `#[cfg(..)]` attributes and
+/// Cargo's `[target.'cfg(..)'.dependencies]` tables.
+///
+/// # Example
+/// ```rust
+/// use build_target::{CfgExpr, Target};
+///
+/// let expr: CfgExpr = r#"cfg(all(unix, target_arch = "x86_64", not(target_env = "musl")))"#.parse().unwrap();
+/// assert!(Target::lookup("x86_64-unknown-linux-gnu").unwrap().matches(&expr));
+/// assert!(!Target::lookup("x86_64-unknown-linux-musl").unwrap().matches(&expr));
+/// assert!(!Target::lookup("x86_64-pc-windows-msvc").unwrap().matches(&expr));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum CfgExpr {
+    /// A bare name such as `unix` or `windows`.
+    Name(String),
+    /// A key value pair such as `target_os = "linux"`.
+    KeyValue {
+        /// The key of the predicate, e.g. `target_os`.
+        key: String,
+        /// The value of the predicate, e.g. `linux`.
+        value: String,
+    },
+    /// Matches if all of the contained expressions match.
+    All(Vec<CfgExpr>),
+    /// Matches if any of the contained expressions matches.
+    Any(Vec<CfgExpr>),
+    /// Matches if the contained expression does not match.
+    Not(Box<CfgExpr>),
+}
+
+impl CfgExpr {
+    /// Parses a `cfg` expression, optionally wrapped in `cfg(..)`.
+    pub fn parse(expr: &str) -> Result<Self, ParseCfgError> {
+        let mut parser = Parser {
+            input: expr,
+            chars: expr.char_indices().peekable(),
+        };
+
+        let parsed = parser.expr()?;
+        let parsed = match parsed {
+            CfgExpr::Name(name) if name == "cfg" && parser.eat('(') => {
+                let inner = parser.expr()?;
+                parser.expect(')')?;
+                inner
+            }
+            parsed => parsed,
+        };
+
+        parser.skip_whitespace();
+        match parser.chars.next() {
+            None => Ok(parsed),
+            Some(_) => Err(parser.error("unexpected trailing characters")),
+        }
+    }
+
+    /// Evaluates this expression against the given [`Target`]. This function is equivalent to [`Target::matches()`].
+    #[must_use]
+    pub fn matches(&self, target: &Target) -> bool {
+        match self {
+            Self::Name(name) => match name.as_str() {
+                "unix" => target.family.contains(&Family::Unix),
+                "windows" => target.family.contains(&Family::Windows),
+                _ => false,
+            },
+            Self::KeyValue { key, value } => {
+                let value = value.as_str();
+                match key.as_str() {
+                    "target_arch" => target.arch.as_str() == value,
+                    "target_endian" => target.endian.as_str() == value,
+                    "target_env" => target.env.as_ref().map_or("", |env| env.as_str()) == value,
+                    "target_abi" => target.abi.as_ref().map_or("", |abi| abi.as_str()) == value,
+                    "target_os" => target.os.as_str() == value,
+                    "target_pointer_width" => target.pointer_width.as_str() == value,
+                    "target_vendor" => target.vendor.as_str() == value,
+                    "target_family" => target.family.iter().any(|family| family.as_str() == value),
+                    "target_feature" => target.features.contains(&TargetFeature::from_str(value)),
+                    "target_has_atomic" => target.atomics.has_atomic(&AtomicWidth::from_str(value)),
+                    "target_has_atomic_load_store" => target
+                        .atomics
+                        .has_atomic_load_store(&AtomicWidth::from_str(value)),
+                    "target_has_atomic_equal_alignment" => target
+                        .atomics
+                        .equal_alignment
+                        .contains(&AtomicWidth::from_str(value)),
+                    _ => false,
+                }
+            }
+            Self::All(exprs) => exprs.iter().all(|expr| expr.matches(target)),
+            Self::Any(exprs) => exprs.iter().any(|expr| expr.matches(target)),
+            Self::Not(expr) => !expr.matches(target),
+        }
+    }
+}
+
+impl FromStr for CfgExpr {
+    type Err = ParseCfgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for CfgExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list(f: &mut fmt::Formatter<'_>, name: &str, exprs: &[CfgExpr]) -> fmt::Result {
+            write!(f, "{name}(")?;
+            for (i, expr) in exprs.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{expr}")?;
+            }
+            f.write_str(")")
+        }
+
+        match self {
+            Self::Name(name) => f.write_str(name),
+            Self::KeyValue { key, value } => write!(f, "{key} = \"{value}\""),
+            Self::All(exprs) => list(f, "all", exprs),
+            Self::Any(exprs) => list(f, "any", exprs),
+            Self::Not(expr) => write!(f, "not({expr})"),
+        }
+    }
+}
+
+impl Target {
+    /// Checks whether this target matches the given `cfg` expression.
+    ///
+    /// Predicates which do not describe the target, such as `debug_assertions` or `feature = ".."`,
+    /// never match.
+    #[must_use]
+    pub fn matches(&self, expr: &CfgExpr) -> bool {
+        expr.matches(self)
+    }
+}
+
+/// Error returned when a string could not be parsed as a [`CfgExpr`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseCfgError {
+    expr: String,
+    position: usize,
+    reason: &'static str,
+}
+
+impl ParseCfgError {
+    /// The string that failed to parse.
+    #[must_use]
+    pub fn expr(&self) -> &str {
+        &self.expr
+    }
+
+    /// The byte offset in the string at which parsing failed.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseCfgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid cfg expression `{}`: {} at offset {}",
+            self.expr, self.reason, self.position
+        )
+    }
+}
+
+impl error::Error for ParseCfgError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn expr(&mut self) -> Result<CfgExpr, ParseCfgError> {
+        let name = self.ident()?;
+
+        if matches!(name.as_str(), "all" | "any" | "not") && self.eat('(') {
+            let mut exprs = Vec::new();
+            while !self.eat(')') {
+                exprs.push(self.expr()?);
+                if !self.eat(',') {
+                    self.expect(')')?;
+                    break;
+                }
+            }
+
+            return match name.as_str() {
+                "all" => Ok(CfgExpr::All(exprs)),
+                "any" => Ok(CfgExpr::Any(exprs)),
+                _ => match <[_; 1]>::try_from(exprs) {
+                    Ok([expr]) => Ok(CfgExpr::Not(Box::new(expr))),
+                    Err(_) => Err(self.error("`not` expects exactly one expression")),
+                },
+            };
+        }
+
+        if self.eat('=') {
+            let value = self.string()?;
+            return Ok(CfgExpr::KeyValue { key: name, value });
+        }
+
+        Ok(CfgExpr::Name(name))
+    }
+
+    fn ident(&mut self) -> Result<String, ParseCfgError> {
+        self.skip_whitespace();
+        let mut ident = String::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            let valid = if ident.is_empty() {
+                c.is_ascii_alphabetic() || c == '_'
+            } else {
+                c.is_ascii_alphanumeric() || c == '_'
+            };
+            if !valid {
+                break;
+            }
+            ident.push(c);
+            self.chars.next();
+        }
+
+        if ident.is_empty() {
+            Err(self.error("expected identifier"))
+        } else {
+            Ok(ident)
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseCfgError> {
+        self.expect('"')?;
+        let mut string = String::new();
+        for (_, c) in self.chars.by_ref() {
+            if c == '"' {
+                return Ok(string);
+            }
+            string.push(c);
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.chars.peek().is_some_and(|&(_, c)| c == expected) {
+            self.chars.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseCfgError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(match expected {
+                '(' => "expected `(`",
+                ')' => "expected `)`",
+                '"' => "expected string",
+                _ => "unexpected character",
+            }))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|(_, c)| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn error(&mut self, reason: &'static str) -> ParseCfgError {
+        ParseCfgError {
+            expr: self.input.to_string(),
+            position: self.chars.peek().map_or(self.input.len(), |&(i, _)| i),
+            reason,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kv(key: &str, value: &str) -> CfgExpr {
+        CfgExpr::KeyValue {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            CfgExpr::parse("unix"),
+            Ok(CfgExpr::Name("unix".to_string()))
+        );
+        assert_eq!(
+            CfgExpr::parse(r#"cfg(target_os = "linux")"#),
+            Ok(kv("target_os", "linux"))
+        );
+        assert_eq!(
+            CfgExpr::parse(
+                r#"all(unix, any(target_arch="x86", target_arch="x86_64",), not(target_env = "musl"))"#
+            ),
+            Ok(CfgExpr::All(vec![
+                CfgExpr::Name("unix".to_string()),
+                CfgExpr::Any(vec![kv("target_arch", "x86"), kv("target_arch", "x86_64")]),
+                CfgExpr::Not(Box::new(kv("target_env", "musl"))),
+            ]))
+        );
+        assert_eq!(CfgExpr::parse("all()"), Ok(CfgExpr::All(vec![])));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(CfgExpr::parse("").is_err());
+        assert!(CfgExpr::parse("not()").is_err());
+        assert!(CfgExpr::parse("not(unix, windows)").is_err());
+        assert!(CfgExpr::parse("all(unix").is_err());
+        assert!(CfgExpr::parse(r#"target_os = "linux"#).is_err());
+        assert!(CfgExpr::parse("target_os = linux").is_err());
+        assert!(CfgExpr::parse("cfg(unix) unix").is_err());
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        let expr = r#"all(unix, any(target_os = "linux", target_os = "android"), not(target_feature = "crt-static"))"#;
+        assert_eq!(CfgExpr::parse(expr).unwrap().to_string(), expr);
+    }
+
+    #[test]
+    fn matches() {
+        let windows = Target::lookup("x86_64-pc-windows-msvc").unwrap();
+        let wasm = Target::lookup("wasm32-unknown-emscripten").unwrap();
+        let thumb = Target::lookup("thumbv7em-none-eabihf").unwrap();
+
+        let check = |expr: &str, target: &Target| target.matches(&expr.parse().unwrap());
+        assert!(check("windows", windows));
+        assert!(!check("unix", windows));
+        assert!(check(r#"target_family = "wasm""#, wasm));
+        assert!(check(r#"all(unix, target_family = "wasm")"#, wasm));
+        assert!(check(r#"target_env = """#, thumb));
+        assert!(check(r#"target_abi = "eabihf""#, thumb));
+        assert!(check(r#"target_has_atomic = "32""#, thumb));
+        assert!(!check(r#"target_has_atomic = "64""#, thumb));
+        assert!(check(r#"target_has_atomic_load_store = "32""#, thumb));
+        assert!(!check(r#"target_has_atomic_load_store = "64""#, thumb));
+
+        let mut thumbv6m = Target::lookup("thumbv6m-none-eabi").unwrap().clone();
+        thumbv6m.atomics.load_store = vec![AtomicWidth::U8, AtomicWidth::U32];
+        thumbv6m.atomics.equal_alignment = vec![AtomicWidth::U32];
+        assert!(check(r#"target_has_atomic_load_store = "8""#, &thumbv6m));
+        assert!(!check(r#"target_has_atomic = "8""#, &thumbv6m));
+        assert!(check(
+            r#"target_has_atomic_equal_alignment = "32""#,
+            &thumbv6m
+        ));
+        assert!(!check(
+            r#"target_has_atomic_equal_alignment = "8""#,
+            &thumbv6m
+        ));
+        assert!(check(r#"target_feature = "sse2""#, windows));
+        assert!(!check("debug_assertions", windows));
+        assert!(check("not(debug_assertions)", windows));
+    }
+}
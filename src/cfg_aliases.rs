@@ -0,0 +1,164 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use crate::{CfgExpr, Target};
+
+/// A predicate over a [`Target`] used to define a cfg alias in [`CfgAliases`].
+///
+/// This is implemented for closures taking a [`Target`] and for [`CfgExpr`].
+pub trait TargetPredicate {
+    /// Checks whether the given target satisfies this predicate.
+    fn matches(&self, target: &Target) -> bool;
+}
+
+impl<F: Fn(&Target) -> bool> TargetPredicate for F {
+    fn matches(&self, target: &Target) -> bool {
+        self(target)
+    }
+}
+
+impl TargetPredicate for CfgExpr {
+    fn matches(&self, target: &Target) -> bool {
+        CfgExpr::matches(self, target)
+    }
+}
+
+/// Builder for custom cfgs that are derived from facts about the build target.
+///
+/// Every declared alias is reported to Cargo using `cargo::rustc-check-cfg` so the `unexpected_cfgs` lint
+/// knows about it, while `cargo::rustc-cfg` is only emitted for the aliases whose predicate matches.
+///
+/// # Example
+/// ```rust no_run
+/// // inside build.rs
+///
+/// use build_target::{Arch, CfgAliases, Os, TargetFeature};
+///
+/// fn main() {
+///     CfgAliases::new()
+///         .alias("apple_mobile", |target: &build_target::Target| {
+///             matches!(target.os, Os::iOS | Os::TvOS | Os::WatchOS | Os::VisionOS)
+///         })
+///         .alias("has_simd", |target: &build_target::Target| {
+///             target.arch == Arch::X86_64 && target.features.contains(&TargetFeature::Sse4_2)
+///         })
+///         .alias_cfg("bare_metal", r#"target_os = "none""#)
+///         .emit();
+/// }
+/// ```
+#[derive(Default)]
+pub struct CfgAliases {
+    aliases: Vec<(String, Box<dyn TargetPredicate>)>,
+}
+
+impl CfgAliases {
+    /// Creates a new builder without any aliases.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a cfg with the given name that is enabled if the predicate matches.
+    #[must_use]
+    pub fn alias(
+        mut self,
+        name: impl Into<String>,
+        predicate: impl TargetPredicate + 'static,
+    ) -> Self {
+        self.aliases.push((name.into(), Box::new(predicate)));
+        self
+    }
+
+    /// Declares a cfg with the given name that is enabled if the given `cfg` expression matches.
+    ///
+    /// # Panics
+    /// Panics if the expression cannot be parsed as a [`CfgExpr`].
+    #[must_use]
+    pub fn alias_cfg(self, name: impl Into<String>, expr: &str) -> Self {
+        let expr = CfgExpr::parse(expr).unwrap_or_else(|err| panic!("{err}"));
+        self.alias(name, expr)
+    }
+
+    /// Gets the names of all declared aliases.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Gets the names of the aliases which are enabled for the given target.
+    pub fn enabled<'a>(&'a self, target: &'a Target) -> impl Iterator<Item = &'a str> {
+        self.aliases
+            .iter()
+            .filter(|(_, predicate)| predicate.matches(target))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Writes the `cargo::rustc-check-cfg` and `cargo::rustc-cfg` directives for the given target.
+    pub fn write_to(&self, target: &Target, out: &mut impl Write) -> io::Result<()> {
+        for name in self.names() {
+            writeln!(out, "cargo::rustc-check-cfg=cfg({name})")?;
+        }
+        for name in self.enabled(target) {
+            writeln!(out, "cargo::rustc-cfg={name}")?;
+        }
+        Ok(())
+    }
+
+    /// Emits the `cargo::rustc-check-cfg` and `cargo::rustc-cfg` directives for the current build target.
+    ///
+    /// # Panics
+    /// Panics if writing to stdout fails.
+    pub fn emit(&self) {
+        self.write_to(&Target::current(), &mut io::stdout().lock())
+            .expect("failed to write cargo directives to stdout");
+    }
+}
+
+impl fmt::Debug for CfgAliases {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CfgAliases")
+            .field("aliases", &self.names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Os;
+
+    fn aliases() -> CfgAliases {
+        CfgAliases::new()
+            .alias("apple_mobile", |target: &Target| {
+                matches!(target.os, Os::iOS | Os::TvOS | Os::WatchOS | Os::VisionOS)
+            })
+            .alias_cfg("bare_metal", r#"target_os = "none""#)
+    }
+
+    #[test]
+    fn enabled() {
+        let aliases = aliases();
+        let ios = Target::lookup("aarch64-apple-ios").unwrap();
+        let thumb = Target::lookup("thumbv7em-none-eabihf").unwrap();
+        let linux = Target::lookup("x86_64-unknown-linux-gnu").unwrap();
+
+        assert_eq!(aliases.enabled(ios).collect::<Vec<_>>(), ["apple_mobile"]);
+        assert_eq!(aliases.enabled(thumb).collect::<Vec<_>>(), ["bare_metal"]);
+        assert_eq!(aliases.enabled(linux).count(), 0);
+    }
+
+    #[test]
+    fn write_to() {
+        let mut out = Vec::new();
+        aliases()
+            .write_to(Target::lookup("thumbv7em-none-eabihf").unwrap(), &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "cargo::rustc-check-cfg=cfg(apple_mobile)\n\
+             cargo::rustc-check-cfg=cfg(bare_metal)\n\
+             cargo::rustc-cfg=bare_metal\n"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Abi, Arch, Endian, Env, Family, Os, PointerWidth, Vendor};
+
+/// Renders a `cargo::rustc-check-cfg` directive declaring the given values as expected for the cfg `name`.
+///
+/// This is useful for crates that pass custom values for well-known cfgs, e.g. `--cfg target_os="myos"`
+/// for out-of-tree targets, and want to extend the set of expected values.
+///
+/// # Example
+/// ```rust
+/// use build_target::{Os, check_cfg_values};
+///
+/// let directive = check_cfg_values("target_os", Os::all().map(|os| os.to_string()).chain(["myos".to_string()]));
+/// assert!(directive.starts_with(r#"cargo::rustc-check-cfg=cfg(target_os, values("aix", "amdhsa""#));
+/// assert!(directive.ends_with(r#", "myos"))"#));
+/// ```
+#[must_use]
+pub fn check_cfg_values<I>(name: &str, values: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut directive = format!("cargo::rustc-check-cfg=cfg({name}, values(");
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            directive.push_str(", ");
+        }
+        directive.push('"');
+        for c in value.as_ref().chars() {
+            if matches!(c, '"' | '\\') {
+                directive.push('\\');
+            }
+            directive.push(c);
+        }
+        directive.push('"');
+    }
+    directive.push_str("))");
+    directive
+}
+
+/// Renders `cargo::rustc-check-cfg` directives declaring all values known to this crate for the target cfgs
+/// `target_arch`, `target_abi`, `target_endian`, `target_env`, `target_family`, `target_os`,
+/// `target_pointer_width` and `target_vendor`.
+///
+/// `target_abi` and `target_env` additionally include the empty string used by targets without an ABI or
+/// environment.
+#[must_use]
+pub fn known_check_cfgs() -> Vec<String> {
+    fn values<T: AsRef<str>>(all: impl Iterator<Item = T>) -> Vec<String> {
+        all.map(|value| value.as_ref().to_string()).collect()
+    }
+    fn with_empty<T: AsRef<str>>(all: impl Iterator<Item = T>) -> Vec<String> {
+        std::iter::once(String::new()).chain(values(all)).collect()
+    }
+
+    vec![
+        check_cfg_values("target_arch", values(Arch::all())),
+        check_cfg_values("target_abi", with_empty(Abi::all())),
+        check_cfg_values("target_endian", values(Endian::all())),
+        check_cfg_values("target_env", with_empty(Env::all())),
+        check_cfg_values("target_family", values(Family::all())),
+        check_cfg_values("target_os", values(Os::all())),
+        check_cfg_values("target_pointer_width", values(PointerWidth::all())),
+        check_cfg_values("target_vendor", values(Vendor::all())),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::targets;
+
+    #[test]
+    fn render() {
+        assert_eq!(
+            check_cfg_values("target_os", ["linux", "my\"os"]),
+            r#"cargo::rustc-check-cfg=cfg(target_os, values("linux", "my\"os"))"#
+        );
+        assert_eq!(
+            check_cfg_values("target_os", [""; 0]),
+            "cargo::rustc-check-cfg=cfg(target_os, values())"
+        );
+    }
+
+    #[test]
+    fn all_covers_known_targets() {
+        for target in targets::all() {
+            assert!(Arch::all().any(|arch| arch == target.arch), "{target}");
+            assert!(Os::all().any(|os| os == target.os), "{target}");
+            assert!(
+                Vendor::all().any(|vendor| vendor == target.vendor),
+                "{target}"
+            );
+        }
+    }
+
+    #[test]
+    fn known() {
+        let cfgs = known_check_cfgs();
+        assert_eq!(cfgs.len(), 8);
+        assert!(cfgs.contains(
+            &r#"cargo::rustc-check-cfg=cfg(target_endian, values("big", "little"))"#.to_string()
+        ));
+        assert!(cfgs.iter().any(|cfg| cfg.starts_with(r#"cargo::rustc-check-cfg=cfg(target_env, values("", "#)));
+    }
+}
@@ -0,0 +1,435 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    Abi, Arch, AtomicSupport, AtomicWidth, Endian, Env, EnvSource, Error, Family, Os,
+    PanicStrategy, PointerWidth, ProcessEnv, RelocationModel, Target, TargetFeature,
+    TargetFeatures, Vendor,
+    json::Json,
+    triple::Components,
+    utils::{build_env, expect_env},
+};
+
+/// Specification of a custom target, i.e. a target defined by a rustc target-spec JSON file instead of being
+/// built into rustc.
+///
+/// The information shared with built-in targets is available as a regular [`Target`] in
+/// [`CustomTargetSpec::target`], the remaining fields hold the settings only custom targets expose.
+///
+/// The widths in [`AtomicSupport::equal_alignment`] are derived from the integer alignments of the `data-layout`
+/// and are left empty if the specification has none.
+///
+/// # Example
+/// ```rust
+/// use build_target::{Arch, CustomTargetSpec, Os};
+///
+/// let spec = CustomTargetSpec::parse("x86_64-kernel", r#"{
+///     "llvm-target": "x86_64-unknown-none",
+///     "data-layout": "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128",
+///     "arch": "x86_64",
+///     "target-endian": "little",
+///     "target-pointer-width": "64",
+///     "os": "none",
+///     "linker-flavor": "ld.lld",
+///     "features": "-mmx,-sse,+soft-float",
+///     "disable-redzone": true
+/// }"#).unwrap();
+/// assert_eq!(spec.target.arch, Arch::X86_64);
+/// assert_eq!(spec.target.os, Os::None);
+/// assert_eq!(spec.linker_flavor.as_deref(), Some("ld.lld"));
+/// assert!(spec.disable_redzone);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomTargetSpec {
+    /// The target described by the specification. Its triple is the name of the specification.
+    pub target: Target,
+    /// The path of the specification file, if it was read from a file.
+    pub path: Option<PathBuf>,
+    /// The target triple passed to LLVM (`llvm-target`).
+    pub llvm_target: Option<String>,
+    /// The LLVM data layout of the target (`data-layout`).
+    pub data_layout: Option<String>,
+    /// The kind of linker used for the target, such as `gcc`, `ld.lld` or `msvc` (`linker-flavor`).
+    pub linker_flavor: Option<String>,
+    /// The linker used for the target (`linker`).
+    pub linker: Option<String>,
+    /// The default CPU of the target (`cpu`).
+    pub cpu: Option<String>,
+    /// The target features explicitly disabled through `-<feature>` in `features`.
+    pub disabled_features: TargetFeatures,
+    /// The largest width in bits for which atomic operations are supported (`max-atomic-width`).
+    pub max_atomic_width: Option<u64>,
+    /// The width of the C `int` type in bits (`target-c-int-width`).
+    pub c_int_width: Option<u64>,
+    /// The panic strategy of the target (`panic-strategy`).
+    pub panic_strategy: Option<PanicStrategy>,
+    /// The default relocation model of the target (`relocation-model`).
+    pub relocation_model: Option<RelocationModel>,
+    /// The default code model of the target, such as `kernel` or `small` (`code-model`).
+    pub code_model: Option<String>,
+    /// Whether the red zone below the stack pointer is disabled (`disable-redzone`).
+    pub disable_redzone: bool,
+}
+
+impl CustomTargetSpec {
+    /// Gets the [`CustomTargetSpec`] of the current target or [`None`] if it is a built-in target.
+    ///
+    /// A target is considered custom if `TARGET` is the path of a `.json` file, or if it is not a built-in
+    /// target and a `<TARGET>.json` file exists in one of the directories listed in `RUST_TARGET_PATH`.
+    ///
+    /// Cargo only passes the file stem of a target-spec path as `TARGET`, e.g. `x86_64-kernel` for
+    /// `--target path/to/x86_64-kernel.json`. If `TARGET` is neither a built-in target nor a triple made of known
+    /// components and no specification is found in `RUST_TARGET_PATH`, an [`Error::InvalidTargetSpec`] is
+    /// returned, as the specification cannot be located.
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the [`CustomTargetSpec`] of the current target. See [`CustomTargetSpec::current()`].
+    pub fn try_current() -> Result<Option<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`CustomTargetSpec`] of the target described by the given [`EnvSource`].
+    /// See [`CustomTargetSpec::current()`].
+    pub fn from_env(source: &impl EnvSource) -> Result<Option<Self>, Error> {
+        let triple = build_env(source, "TARGET")?;
+        if Path::new(&triple)
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            return Self::from_file(triple).map(Some);
+        }
+        if Target::lookup(&triple).is_some() {
+            return Ok(None);
+        }
+
+        let target_path = source.var_os("RUST_TARGET_PATH").unwrap_or_default();
+        if let Some(path) = env::split_paths(&target_path)
+            .map(|dir| dir.join(format!("{triple}.json")))
+            .find(|path| path.is_file())
+        {
+            return Self::from_file(path).map(Some);
+        }
+
+        // built-in targets not known to this crate still consist of known components
+        let is_known_triple = Components::split(&triple).is_some_and(|components| {
+            !matches!(components.arch(), Arch::Other(_)) && !matches!(components.os(), Os::Other(_))
+        });
+        if is_known_triple {
+            Ok(None)
+        } else {
+            Err(Error::InvalidTargetSpec {
+                name: triple,
+                reason: "not a known target and no specification found in RUST_TARGET_PATH"
+                    .to_string(),
+            })
+        }
+    }
+
+    /// Reads the specification from the given file. The name of the target is the file name without extension.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let json = fs::read_to_string(path).map_err(|err| Error::InvalidTargetSpec {
+            name: name.clone(),
+            reason: err.to_string(),
+        })?;
+
+        let mut spec = Self::parse(&name, &json)?;
+        spec.path = Some(path.to_path_buf());
+        Ok(spec)
+    }
+
+    /// Parses the given target-spec JSON of the target with the given name.
+    pub fn parse(name: &str, json: &str) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::InvalidTargetSpec {
+            name: name.to_string(),
+            reason,
+        };
+
+        let json = Json::parse(json).map_err(invalid)?;
+        let string = |key: &str| json.get(key).and_then(Json::as_str);
+
+        let arch = string("arch").ok_or_else(|| invalid("missing field `arch`".to_string()))?;
+        let pointer_width = match json.get("target-pointer-width") {
+            Some(Json::String(width)) => width.parse().ok(),
+            Some(width) => width.as_u64(),
+            None => None,
+        }
+        .ok_or_else(|| invalid("missing or invalid field `target-pointer-width`".to_string()))?;
+        let family = match json.get("target-family") {
+            Some(Json::String(family)) => vec![Family::from_str(family.as_str())],
+            Some(families) => families
+                .as_array()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(Json::as_str)
+                .map(Family::from_str)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let mut features = TargetFeatures::new();
+        let mut disabled_features = TargetFeatures::new();
+        for feature in string("features").unwrap_or_default().split(',') {
+            if let Some(feature) = feature.strip_prefix('+') {
+                features.insert(TargetFeature::from_str(feature));
+            } else if let Some(feature) = feature.strip_prefix('-') {
+                disabled_features.insert(TargetFeature::from_str(feature));
+            }
+        }
+
+        let max_atomic_width = json.get("max-atomic-width").and_then(Json::as_u64);
+        let target = Target {
+            arch: Arch::from_str(arch),
+            endian: Endian::from_str(string("target-endian").unwrap_or("little")),
+            env: string("env")
+                .filter(|env| !env.is_empty())
+                .map(Env::from_str),
+            abi: string("abi")
+                .filter(|abi| !abi.is_empty())
+                .map(Abi::from_str),
+            os: Os::from_str(string("os").unwrap_or("none")),
+            pointer_width: PointerWidth::from_str(pointer_width.to_string()),
+            family,
+            features,
+            atomics: atomic_support(&json, pointer_width, max_atomic_width),
+            vendor: Vendor::from_str(string("vendor").unwrap_or("unknown")),
+            triple: name.to_string(),
+        };
+
+        Ok(Self {
+            target,
+            path: None,
+            llvm_target: string("llvm-target").map(str::to_string),
+            data_layout: string("data-layout").map(str::to_string),
+            linker_flavor: string("linker-flavor").map(str::to_string),
+            linker: string("linker").map(str::to_string),
+            cpu: string("cpu").map(str::to_string),
+            disabled_features,
+            max_atomic_width,
+            c_int_width: match json.get("target-c-int-width") {
+                Some(Json::String(width)) => width.parse().ok(),
+                Some(width) => width.as_u64(),
+                None => None,
+            },
+            panic_strategy: string("panic-strategy").map(PanicStrategy::from_str),
+            relocation_model: string("relocation-model").map(RelocationModel::from_str),
+            code_model: string("code-model").map(str::to_string),
+            disable_redzone: json
+                .get("disable-redzone")
+                .and_then(Json::as_bool)
+                .unwrap_or(false),
+        })
+    }
+}
+
+/// Derives the atomic support of a target from its specification.
+fn atomic_support(json: &Json, pointer_width: u64, max_atomic_width: Option<u64>) -> AtomicSupport {
+    let min_atomic_width = json
+        .get("min-atomic-width")
+        .and_then(Json::as_u64)
+        .unwrap_or(8);
+    let atomic_range = min_atomic_width..=max_atomic_width.unwrap_or(pointer_width);
+    let mut load_store = [8, 16, 32, 64, 128]
+        .into_iter()
+        .filter(|width| atomic_range.contains(width))
+        .map(|width| AtomicWidth::from_str(width.to_string()))
+        .collect::<Vec<_>>();
+    if atomic_range.contains(&pointer_width) {
+        load_store.push(AtomicWidth::Ptr);
+    }
+    // without compare-and-swap only atomic loads and stores are available, e.g. on thumbv6m
+    let atomic_cas = json
+        .get("atomic-cas")
+        .and_then(Json::as_bool)
+        .unwrap_or(true);
+    let has_atomic = if atomic_cas {
+        load_store.clone()
+    } else {
+        Vec::new()
+    };
+    // whether atomics have the alignment of the plain integers can only be told from the data layout
+    let equal_alignment = match json.get("data-layout").and_then(Json::as_str) {
+        Some(data_layout) => load_store
+            .iter()
+            .filter(|width| {
+                let bits = width.bits().map_or(pointer_width, u64::from);
+                integer_align(data_layout, bits) == Some(bits)
+            })
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    };
+
+    AtomicSupport {
+        has_atomic,
+        load_store,
+        equal_alignment,
+    }
+}
+
+/// Gets the ABI alignment in bits of the integer type with the given width according to the given LLVM data
+/// layout, falling back to the defaults of LLVM for types the layout does not specify.
+fn integer_align(data_layout: &str, bits: u64) -> Option<u64> {
+    let explicit = data_layout.split('-').find_map(|spec| {
+        let (width, align) = spec.strip_prefix('i')?.split_once(':')?;
+        (width.parse() == Ok(bits)).then(|| align.split(':').next()?.parse().ok())?
+    });
+    // unspecified integer types larger than 64 bits use the alignment of a smaller one
+    explicit.or(match bits {
+        8 | 16 | 32 => Some(bits),
+        64 => Some(32),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EnvOverride;
+    use std::collections::HashMap;
+
+    const AARCH64_KERNEL: &str = r#"{
+        "llvm-target": "aarch64-unknown-none",
+        "data-layout": "e-m:e-p270:32:32-p271:32:32-p272:64:64-i8:8:32-i16:16:32-i64:64-i128:128-n32:64-S128-Fn32",
+        "arch": "aarch64",
+        "target-endian": "little",
+        "target-pointer-width": 64,
+        "target-c-int-width": 32,
+        "os": "none",
+        "vendor": "acme",
+        "target-family": ["unix"],
+        "max-atomic-width": 128,
+        "features": "+v8a,+strict-align,-neon,-fp-armv8",
+        "panic-strategy": "abort",
+        "relocation-model": "static"
+    }"#;
+
+    #[test]
+    fn parse() {
+        let spec = CustomTargetSpec::parse("aarch64-kernel", AARCH64_KERNEL).unwrap();
+        assert_eq!(spec.target.triple, "aarch64-kernel");
+        assert_eq!(spec.target.arch, Arch::AArch64);
+        assert_eq!(spec.target.pointer_width, PointerWidth::U64);
+        assert_eq!(spec.target.env, None);
+        assert_eq!(spec.target.vendor, Vendor::Other("acme".to_string()));
+        assert_eq!(spec.target.family, [Family::Unix]);
+        assert_eq!(spec.target.features.to_string(), "strict-align,v8a");
+        assert_eq!(spec.disabled_features.to_string(), "fp-armv8,neon");
+        assert_eq!(spec.target.atomics.max_atomic_width(), Some(128));
+        assert!(spec.target.atomics.has_atomic(&AtomicWidth::Ptr));
+        assert_eq!(spec.c_int_width, Some(32));
+        assert_eq!(spec.panic_strategy, Some(PanicStrategy::Abort));
+        assert_eq!(spec.relocation_model, Some(RelocationModel::Static));
+        assert!(!spec.disable_redzone);
+    }
+
+    #[test]
+    fn atomics_without_cas() {
+        let spec = CustomTargetSpec::parse(
+            "thumbv6m-custom",
+            r#"{
+                "arch": "arm",
+                "target-pointer-width": "32",
+                "max-atomic-width": 32,
+                "atomic-cas": false
+            }"#,
+        )
+        .unwrap();
+        let widths = vec![
+            AtomicWidth::U8,
+            AtomicWidth::U16,
+            AtomicWidth::U32,
+            AtomicWidth::Ptr,
+        ];
+        assert_eq!(
+            spec.target.atomics,
+            AtomicSupport {
+                has_atomic: Vec::new(),
+                load_store: widths,
+                equal_alignment: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn atomics_equal_alignment() {
+        let spec = CustomTargetSpec::parse("aarch64-kernel", AARCH64_KERNEL).unwrap();
+        assert_eq!(
+            spec.target.atomics.equal_alignment,
+            spec.target.atomics.load_store
+        );
+
+        // 64-bit integers are only 4-byte aligned on i686
+        let spec = CustomTargetSpec::parse(
+            "i686-kernel",
+            r#"{
+                "data-layout": "e-m:e-p:32:32-p270:32:32-p271:32:32-p272:64:64-i128:128-f64:32:64-f80:32-n8:16:32-S128",
+                "arch": "x86",
+                "target-pointer-width": "32",
+                "max-atomic-width": 64
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            spec.target.atomics.equal_alignment,
+            [
+                AtomicWidth::U8,
+                AtomicWidth::U16,
+                AtomicWidth::U32,
+                AtomicWidth::Ptr
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            CustomTargetSpec::parse("foo", r#"{"target-pointer-width": "64"}"#),
+            Err(Error::InvalidTargetSpec {
+                name: "foo".to_string(),
+                reason: "missing field `arch`".to_string()
+            })
+        );
+        assert!(CustomTargetSpec::parse("foo", "{").is_err());
+    }
+
+    #[test]
+    fn from_env() {
+        let dir = env::temp_dir().join(format!("build-target-spec-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("aarch64-kernel.json"), AARCH64_KERNEL).unwrap();
+
+        let env = HashMap::from([("TARGET", "x86_64-unknown-linux-gnu")]);
+        assert_eq!(CustomTargetSpec::from_env(&env), Ok(None));
+
+        let env = EnvOverride::new(env)
+            .set("TARGET", "aarch64-kernel")
+            .set("RUST_TARGET_PATH", &dir);
+        let spec = CustomTargetSpec::from_env(&env).unwrap().unwrap();
+        assert_eq!(spec.path, Some(dir.join("aarch64-kernel.json")));
+
+        let env = env.set("TARGET", dir.join("aarch64-kernel.json"));
+        let spec = CustomTargetSpec::from_env(&env).unwrap().unwrap();
+        assert_eq!(spec.target.triple, "aarch64-kernel");
+
+        // cargo only passes the file stem of the specification
+        let env = HashMap::from([("TARGET", "x86_64-kernel")]);
+        assert!(matches!(
+            CustomTargetSpec::from_env(&env),
+            Err(Error::InvalidTargetSpec { name, .. }) if name == "x86_64-kernel"
+        ));
+        let env = HashMap::from([("TARGET", "riscv64gc-unknown-linux-future")]);
+        assert_eq!(CustomTargetSpec::from_env(&env), Ok(None));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,4 +1,7 @@
-use crate::utils::{build_env_opt, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
 use std::fmt;
 
 define_target_enum! {
@@ -14,6 +17,10 @@ define_target_enum! {
     pub enum Env {
         /// The GNU C Library (glibc)
         Gnu => "gnu",
+        /// Mac Catalyst, iOS apps running on macOS
+        MacAbi => "macabi",
+        /// Managarm C library
+        Mlibc => "mlibc",
         /// Microsoft Visual C(++)
         Msvc => "msvc",
         /// Clean, efficient, standards-conformant libc implementation.
@@ -32,12 +39,17 @@ define_target_enum! {
         OhOS => "ohos",
         P1 => "p1",
         P2 => "p2",
+        P3 => "p3",
         /// Relibc environment
         Relibc => "relibc",
         /// Intel Software Guard Extensions (SGX) Enclave
         Sgx => "sgx",
+        /// Apple platform simulator
+        Sim => "sim",
         /// C library for developing embedded Linux systems
-        UClibc => "uclibc"
+        UClibc => "uclibc",
+        /// VEX V5 runtime environment
+        V5 => "v5",
     }
 
     as_str_doc = "String representing this environment which matches `#[cfg(target_env)]`.",
@@ -48,7 +60,17 @@ impl Env {
     /// Gets the current target [`Env`].
     #[must_use]
     pub fn target() -> Option<Self> {
-        build_env_opt("CARGO_CFG_TARGET_ENV").map(Self::from_str)
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`Env`].
+    pub fn try_target() -> Result<Option<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`Env`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Option<Self>, Error> {
+        build_env_opt(env, "CARGO_CFG_TARGET_ENV").map(|value| value.map(Self::from_str))
     }
 }
 
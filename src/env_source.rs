@@ -0,0 +1,257 @@
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
+    env,
+    ffi::{OsStr, OsString},
+    hash::{BuildHasher, Hash},
+};
+
+/// A source of build environment variables.
+///
+/// All accessors of this crate read from the environment of the current process ([`ProcessEnv`]) by default.
+/// The `from_env` functions such as [`Target::from_env()`](crate::Target::from_env) accept any [`EnvSource`]
+/// instead, which allows simulating the build environment of other targets, e.g. in tests.
+///
+/// # Example
+/// ```rust
+/// use std::collections::HashMap;
+/// use build_target::{Abi, Arch, Os, Target};
+///
+/// let env = HashMap::from([
+///     ("TARGET", "aarch64-apple-ios-sim"),
+///     ("CARGO_CFG_TARGET_ARCH", "aarch64"),
+///     ("CARGO_CFG_TARGET_ENDIAN", "little"),
+///     ("CARGO_CFG_TARGET_ENV", "sim"),
+///     ("CARGO_CFG_TARGET_ABI", "sim"),
+///     ("CARGO_CFG_TARGET_OS", "ios"),
+///     ("CARGO_CFG_TARGET_POINTER_WIDTH", "64"),
+///     ("CARGO_CFG_TARGET_FAMILY", "unix"),
+///     ("CARGO_CFG_TARGET_VENDOR", "apple"),
+/// ]);
+///
+/// let target = Target::from_env(&env).unwrap();
+/// assert_eq!(target.arch, Arch::AArch64);
+/// assert_eq!(target.os, Os::iOS);
+/// assert_eq!(target.abi, Some(Abi::Sim));
+/// ```
+pub trait EnvSource {
+    /// Gets the value of the variable with the given name or [`None`] if it is not defined.
+    fn var_os(&self, name: &str) -> Option<OsString>;
+
+    /// Gets the names of all defined variables.
+    ///
+    /// This is only needed for variables whose names are not known upfront, such as `CARGO_FEATURE_<name>`.
+    /// The default implementation returns no names.
+    fn var_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl<T: EnvSource + ?Sized> EnvSource for &T {
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        (**self).var_os(name)
+    }
+
+    fn var_names(&self) -> Vec<String> {
+        (**self).var_names()
+    }
+}
+
+/// [`EnvSource`] reading from the environment of the current process, i.e. the variables set by Cargo
+/// when running a build script.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ProcessEnv;
+
+impl EnvSource for ProcessEnv {
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        env::var_os(name)
+    }
+
+    fn var_names(&self) -> Vec<String> {
+        env::vars_os()
+            .filter_map(|(name, _)| name.into_string().ok())
+            .collect()
+    }
+}
+
+impl<K, V, S> EnvSource for HashMap<K, V, S>
+where
+    K: Borrow<str> + Eq + Hash,
+    V: AsRef<OsStr>,
+    S: BuildHasher,
+{
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        self.get(name).map(|value| value.as_ref().to_os_string())
+    }
+
+    fn var_names(&self) -> Vec<String> {
+        self.keys().map(|name| name.borrow().to_string()).collect()
+    }
+}
+
+impl<K, V> EnvSource for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: AsRef<OsStr>,
+{
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        self.get(name).map(|value| value.as_ref().to_os_string())
+    }
+
+    fn var_names(&self) -> Vec<String> {
+        self.keys().map(|name| name.borrow().to_string()).collect()
+    }
+}
+
+/// [`EnvSource`] that overrides or removes individual variables of another [`EnvSource`] without touching it.
+///
+/// In contrast to [`std::env::set_var`], the overrides only apply to the accessors this source is passed to.
+///
+/// # Example
+/// ```rust no_run
+/// // inside build.rs
+///
+/// use build_target::{EnvOverride, ProcessEnv, Target};
+///
+/// fn main() {
+///     let env = EnvOverride::new(ProcessEnv).set("CARGO_CFG_TARGET_FEATURE", "");
+///     let target_without_features = Target::from_env(&env).unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnvOverride<E> {
+    base: E,
+    overrides: HashMap<String, Option<OsString>>,
+}
+
+impl<E: EnvSource> EnvOverride<E> {
+    /// Creates a new [`EnvOverride`] without any overrides on top of the given source.
+    pub fn new(base: E) -> Self {
+        Self {
+            base,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Overrides the variable with the given name.
+    #[must_use]
+    pub fn set(mut self, name: impl Into<String>, value: impl Into<OsString>) -> Self {
+        self.overrides.insert(name.into(), Some(value.into()));
+        self
+    }
+
+    /// Hides the variable with the given name as if it was not defined.
+    #[must_use]
+    pub fn remove(mut self, name: impl Into<String>) -> Self {
+        self.overrides.insert(name.into(), None);
+        self
+    }
+}
+
+impl<E: EnvSource> EnvSource for EnvOverride<E> {
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        match self.overrides.get(name) {
+            Some(value) => value.clone(),
+            None => self.base.var_os(name),
+        }
+    }
+
+    fn var_names(&self) -> Vec<String> {
+        let mut names = self
+            .base
+            .var_names()
+            .into_iter()
+            .filter(|name| !self.overrides.contains_key(name))
+            .collect::<Vec<_>>();
+        names.extend(
+            self.overrides
+                .iter()
+                .filter(|(_, value)| value.is_some())
+                .map(|(name, _)| name.clone()),
+        );
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Arch, Env, Error, Target, TargetFeatures};
+
+    fn ios_sim() -> HashMap<&'static str, &'static str> {
+        HashMap::from([
+            ("TARGET", "aarch64-apple-ios-sim"),
+            ("CARGO_CFG_TARGET_ARCH", "aarch64"),
+            ("CARGO_CFG_TARGET_ENDIAN", "little"),
+            ("CARGO_CFG_TARGET_ENV", "sim"),
+            ("CARGO_CFG_TARGET_ABI", "sim"),
+            ("CARGO_CFG_TARGET_OS", "ios"),
+            ("CARGO_CFG_TARGET_POINTER_WIDTH", "64"),
+            ("CARGO_CFG_TARGET_FAMILY", "unix"),
+            ("CARGO_CFG_TARGET_VENDOR", "apple"),
+            ("CARGO_CFG_TARGET_HAS_ATOMIC", "8,16,32,64,128,ptr"),
+        ])
+    }
+
+    #[test]
+    fn from_map() {
+        let target = Target::from_env(&ios_sim()).unwrap();
+        let expected = Target::lookup("aarch64-apple-ios-sim").unwrap();
+        assert_eq!(
+            target,
+            Target {
+                features: TargetFeatures::new(),
+                ..expected.clone()
+            }
+        );
+    }
+
+    #[test]
+    fn overrides() {
+        let env = EnvOverride::new(ios_sim())
+            .set("CARGO_CFG_TARGET_ENV", "macabi")
+            .remove("CARGO_CFG_TARGET_ARCH");
+        assert_eq!(Env::from_env(&env), Ok(Some(Env::MacAbi)));
+        assert_eq!(
+            Arch::from_env(&env),
+            Err(Error::MissingVariables(vec![
+                "CARGO_CFG_TARGET_ARCH".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn override_var_names() {
+        let env = EnvOverride::new(HashMap::from([("A", "1"), ("B", "2")]))
+            .set("C", "3")
+            .remove("A");
+        let mut names = env.var_names();
+        names.sort();
+        assert_eq!(names, ["B", "C"]);
+    }
+
+    #[test]
+    fn reports_all_missing_variables() {
+        let env = EnvOverride::new(ios_sim())
+            .remove("CARGO_CFG_TARGET_ARCH")
+            .remove("CARGO_CFG_TARGET_OS");
+        assert_eq!(
+            Target::from_env(&env),
+            Err(Error::MissingVariables(vec![
+                "CARGO_CFG_TARGET_ARCH".to_string(),
+                "CARGO_CFG_TARGET_OS".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn not_in_build_script() {
+        let env = HashMap::<String, String>::new();
+        assert_eq!(
+            Target::from_env(&env),
+            Err(Error::NotInBuildScript {
+                name: "CARGO_CFG_TARGET_ARCH".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::{ffi::OsString, fmt};
+
+use crate::ParseTargetError;
+
+/// Error returned when reading the build environment fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// One or more required environment variables are not defined.
+    MissingVariables(Vec<String>),
+    /// An environment variable is defined but its value is not valid unicode.
+    NotUnicode {
+        /// The name of the variable.
+        name: String,
+        /// The raw value of the variable.
+        value: OsString,
+    },
+    /// A required environment variable is not defined because the crate is not used from within a build script.
+    NotInBuildScript {
+        /// The name of the first variable that was not defined.
+        name: String,
+    },
+    /// An environment variable is defined but its value could not be parsed.
+    InvalidValue {
+        /// The name of the variable.
+        name: String,
+        /// The value of the variable.
+        value: String,
+    },
+    /// A variable does not contain a valid target triple.
+    InvalidTriple(ParseTargetError),
+    /// A line of `rustc --print cfg` output could not be parsed.
+    InvalidRustcCfg {
+        /// The line that failed to parse.
+        line: String,
+    },
+    /// A custom target specification could not be read or parsed.
+    InvalidTargetSpec {
+        /// The name of the custom target.
+        name: String,
+        /// Why the specification is invalid.
+        reason: String,
+    },
+    /// A version such as the release of rustc could not be parsed.
+    InvalidVersion(String),
+    /// Running rustc failed.
+    Rustc(String),
+}
+
+impl Error {
+    /// Merges two errors into one so that all missing variables are reported at once.
+    pub(crate) fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::MissingVariables(mut names), Self::MissingVariables(other)) => {
+                names.extend(other);
+                Self::MissingVariables(names)
+            }
+            (Self::MissingVariables(_), other) => other,
+            (err, _) => err,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingVariables(names) => {
+                let plural = if names.len() == 1 { "" } else { "s" };
+                write!(f, "environment variable{plural} ")?;
+                for (i, name) in names.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{name}")?;
+                }
+                f.write_str(" not defined")
+            }
+            Self::NotUnicode { name, value } => {
+                write!(
+                    f,
+                    "environment variable {name} is not valid unicode: {}",
+                    value.to_string_lossy()
+                )
+            }
+            Self::NotInBuildScript { name } => {
+                let crate_name = std::env!("CARGO_CRATE_NAME").replace('_', "-");
+                write!(
+                    f,
+                    "environment variable {name} not defined, are you using {crate_name} outside of build.rs?"
+                )
+            }
+            Self::InvalidValue { name, value } => {
+                write!(
+                    f,
+                    "environment variable {name} has an invalid value `{value}`"
+                )
+            }
+            Self::InvalidTriple(err) => err.fmt(f),
+            Self::InvalidRustcCfg { line } => write!(f, "invalid rustc cfg `{line}`"),
+            Self::InvalidTargetSpec { name, reason } => {
+                write!(f, "invalid target specification `{name}`: {reason}")
+            }
+            Self::InvalidVersion(version) => write!(f, "invalid version `{version}`"),
+            Self::Rustc(message) => write!(f, "rustc failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidTriple(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseTargetError> for Error {
+    fn from(err: ParseTargetError) -> Self {
+        Self::InvalidTriple(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_missing_variables() {
+        let error = Error::MissingVariables(vec!["A".to_string()])
+            .merge(Error::MissingVariables(vec!["B".to_string()]));
+        assert_eq!(
+            error,
+            Error::MissingVariables(vec!["A".to_string(), "B".to_string()])
+        );
+        assert_eq!(error.to_string(), "environment variables A, B not defined");
+    }
+
+    #[test]
+    fn merge_keeps_other_errors() {
+        let not_in_build_script = Error::NotInBuildScript {
+            name: "TARGET".to_string(),
+        };
+        assert_eq!(
+            Error::MissingVariables(vec!["A".to_string()]).merge(not_in_build_script.clone()),
+            not_in_build_script
+        );
+        assert_eq!(
+            not_in_build_script
+                .clone()
+                .merge(Error::MissingVariables(vec!["A".to_string()])),
+            not_in_build_script
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use crate::utils::{build_env_opt, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
 
 define_target_enum! {
     /// A more generic description of a target, such as the family of
@@ -22,13 +25,24 @@ define_target_enum! {
 
 impl Family {
     /// Gets the current target [`Family`]s.
+    #[must_use]
     pub fn target() -> Vec<Self> {
-        build_env_opt("CARGO_CFG_TARGET_FAMILY")
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`Family`]s.
+    pub fn try_target() -> Result<Vec<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`Family`]s from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Vec<Self>, Error> {
+        Ok(build_env_opt(env, "CARGO_CFG_TARGET_FAMILY")?
             .unwrap_or_default()
             .split(',')
             .filter(|s| !s.is_empty())
             .map(Self::from_str)
-            .collect()
+            .collect())
     }
 }
 
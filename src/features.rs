@@ -0,0 +1,309 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env_opt, expect_env},
+};
+
+/// Set of Cargo features enabled for the package whose build script is running.
+///
+/// Cargo exposes every enabled feature as a `CARGO_FEATURE_<name>` variable, where the name is upper-cased and
+/// dashes are replaced by underscores. The original names are restored from the `[features]` table and the
+/// optional dependencies in `CARGO_MANIFEST_DIR/Cargo.toml`. Features missing from the manifest, e.g. because it
+/// could not be read, are reported in lower-case with underscores instead.
+///
+/// # Example
+/// ```rust no_run
+/// // inside build.rs
+///
+/// use build_target::{Arch, Features, Target, TargetFeature};
+///
+/// fn main() {
+///     let target = Target::current();
+///     if Features::current().contains("simd")
+///         && target.arch == Arch::X86_64
+///         && target.features.contains(&TargetFeature::Sse4_2)
+///     {
+///         println!("cargo:rustc-cfg=use_simd");
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Features(BTreeMap<String, String>);
+
+impl Features {
+    /// Creates an empty set of features.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the [`Features`] enabled for the current build.
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the [`Features`] enabled for the current build.
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`Features`] enabled for the build described by the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        let manifest = build_env_opt(env, "CARGO_MANIFEST_DIR")?
+            .and_then(|dir| fs::read_to_string(Path::new(&dir).join("Cargo.toml")).ok())
+            .unwrap_or_default();
+        let names = env
+            .var_names()
+            .into_iter()
+            .filter_map(|name| Some(name.strip_prefix("CARGO_FEATURE_")?.to_string()))
+            .collect::<Vec<_>>();
+        Ok(Self::from_env_names(&names, &manifest))
+    }
+
+    /// Restores the original feature names from the given `CARGO_FEATURE_<name>` suffixes using the given manifest.
+    pub(crate) fn from_env_names(names: &[String], manifest: &str) -> Self {
+        let declared = manifest_features(manifest);
+        names
+            .iter()
+            .map(|env_name| {
+                declared
+                    .iter()
+                    .find(|name| mangle(name) == *env_name)
+                    .cloned()
+                    .unwrap_or_else(|| env_name.to_ascii_lowercase())
+            })
+            .collect()
+    }
+
+    /// Checks whether the feature with the given name is enabled.
+    ///
+    /// The name is compared the same way Cargo does it, so `serde-json` and `serde_json` are equivalent.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(&mangle(name))
+    }
+
+    /// Adds the feature with the given name to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, name: impl Into<String>) -> bool {
+        let name = name.into();
+        self.0.insert(mangle(&name), name).is_none()
+    }
+
+    /// Removes the feature with the given name from the set, returning whether it was present.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.0.remove(&mangle(name)).is_some()
+    }
+
+    /// Gets the number of enabled features.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether no features are enabled.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the names of the enabled features.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.values().map(String::as_str)
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Features {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut features = Self::new();
+        features.extend(iter);
+        features
+    }
+}
+
+impl<S: Into<String>> Extend<S> for Features {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        for name in iter {
+            self.insert(name);
+        }
+    }
+}
+
+impl fmt::Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, feature) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(feature)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a feature name to the suffix of its `CARGO_FEATURE_<name>` variable.
+fn mangle(name: &str) -> String {
+    name.to_ascii_uppercase().replace('-', "_")
+}
+
+/// Collects the names of all features a manifest can enable, i.e. the keys of the `[features]` table and
+/// the optional dependencies.
+///
+/// This is not a full TOML parser but handles the way manifests are usually written.
+fn manifest_features(manifest: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut section = "";
+    // nesting depth of an array spanning multiple lines, whose lines are not key-value pairs
+    let mut array_depth = 0;
+    for line in manifest.lines().map(str::trim) {
+        if array_depth > 0 {
+            array_depth += bracket_balance(line);
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[') {
+            section = header
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .unwrap_or("")
+                .trim();
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+        array_depth = bracket_balance(value).max(0);
+        let value = value.replace(' ', "");
+
+        if section == "features" {
+            names.push(key.to_string());
+        } else if section.ends_with("dependencies") {
+            // `foo = { version = "1", optional = true }` or `foo.optional = true`
+            match key.strip_suffix(".optional") {
+                Some(dependency) if value.starts_with("true") => names.push(dependency.to_string()),
+                None if value.contains("optional=true") => names.push(key.to_string()),
+                _ => {}
+            }
+        } else if key == "optional" && value.starts_with("true") {
+            // `[dependencies.foo]` table
+            if let Some((_, dependency)) = section.rsplit_once("dependencies.") {
+                names.push(
+                    dependency
+                        .trim_matches(|c| c == '"' || c == '\'')
+                        .to_string(),
+                );
+            }
+        }
+    }
+    names
+}
+
+/// Counts the opening minus the closing brackets of a TOML value, ignoring those in strings and comments.
+fn bracket_balance(value: &str) -> i32 {
+    let mut balance = 0;
+    let mut quote = None;
+    for c in value.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => break,
+            (None, '[') => balance += 1,
+            (None, ']') => balance -= 1,
+            _ => {}
+        }
+    }
+    balance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[package]
+name = "foo"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1", optional = true }
+rand_core = "0.6"
+tokio-util.version = "0.7"
+tokio-util.optional = true
+
+[target.'cfg(unix)'.dependencies.libc-sys]
+version = "0.2"
+optional = true
+
+[features]
+default = ["simd-x86"]
+simd-x86 = [
+    "serde",
+]
+# unused = []
+"no_std" = []
+with-array = [
+    "serde?/std", # std = [ in a comment
+    "rand_core/getrandom" ,
+]
+"#;
+
+    fn env_names(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn manifest() {
+        assert_eq!(
+            manifest_features(MANIFEST),
+            [
+                "serde",
+                "tokio-util",
+                "libc-sys",
+                "default",
+                "simd-x86",
+                "no_std",
+                "with-array"
+            ]
+        );
+    }
+
+    #[test]
+    fn restores_names() {
+        let features = Features::from_env_names(
+            &env_names(&[
+                "DEFAULT",
+                "SIMD_X86",
+                "NO_STD",
+                "LIBC_SYS",
+                "UNKNOWN_FEATURE",
+            ]),
+            MANIFEST,
+        );
+        assert_eq!(
+            features.iter().collect::<Vec<_>>(),
+            [
+                "default",
+                "libc-sys",
+                "no_std",
+                "simd-x86",
+                "unknown_feature"
+            ]
+        );
+        assert!(features.contains("simd-x86"));
+        assert!(features.contains("simd_x86"));
+        assert!(!features.contains("serde"));
+    }
+
+    #[test]
+    fn without_manifest() {
+        let features = Features::from_env_names(&env_names(&["SIMD_X86"]), "");
+        assert_eq!(features.to_string(), "simd_x86");
+        assert!(features.contains("simd-x86"));
+    }
+}
@@ -29,17 +29,76 @@
 //!     let arch   = build_target::target_arch();   // eg. "x86_64", "aarch64", ...
 //!     let endian = build_target::target_endian(); // eg. "big", "little", ...
 //!     let env    = build_target::target_env();    // eg. "gnu", "msvc", ...
+//!     let abi    = build_target::target_abi();    // eg. "eabihf", "sim", ...
 //!     let family = build_target::target_family(); // eg. "windows", "unix", ...
 //!     let pw     = build_target::target_pointer_width(); // eg. "32", "64", ...
 //!     let os     = build_target::target_os();     // eg. "android", "linux", ...
 //!     let vendor = build_target::target_vendor(); // eg. "apple", "unknown", ...
+//!     let features = build_target::target_features(); // eg. "sse2", "neon", ...
 //!     let triple = build_target::target_triple(); // eg. "x86_64-unknown-linux-gnu", ...
 //! }
 //! ```
+//!
+//! Reads the current build target without panicking if the build environment is incomplete.
+//! ```rust no_run
+//! // inside build.rs
+//!
+//! fn main() {
+//!     match build_target::Target::try_current() {
+//!         Ok(target) => println!("cargo:warning=building for {target}"),
+//!         Err(err) => println!("cargo:warning=unknown build target: {err}"),
+//!     }
+//! }
+//! ```
+//!
+//! Compares the host with the current build target.
+//! ```rust no_run
+//! // inside build.rs
+//!
+//! fn main() {
+//!     let host = build_target::host();
+//!     if build_target::is_cross_compiling() {
+//!         println!("cargo:warning=cross compiling from {host} to {}", build_target::target());
+//!     }
+//! }
+//! ```
+
+mod abi;
+pub use abi::*;
+
+mod arm;
+pub use arm::*;
 
 mod arch;
 pub use arch::*;
 
+mod atomic;
+pub use atomic::*;
+
+mod availability;
+pub use availability::*;
+
+mod build_config;
+pub use build_config::*;
+
+mod build_context;
+pub use build_context::*;
+
+mod c_data_model;
+pub use c_data_model::*;
+
+mod cfg;
+pub use cfg::*;
+
+mod cfg_aliases;
+pub use cfg_aliases::*;
+
+mod check_cfg;
+pub use check_cfg::*;
+
+mod custom_target;
+pub use custom_target::*;
+
 mod endian;
 pub use endian::*;
 
@@ -49,9 +108,15 @@ pub use env::*;
 mod family;
 pub use family::*;
 
+mod features;
+pub use features::*;
+
 mod os;
 pub use os::*;
 
+mod target_feature;
+pub use target_feature::*;
+
 mod pointer_width;
 pub use pointer_width::*;
 
@@ -64,15 +129,52 @@ pub use vendor::*;
 mod target;
 pub use target::*;
 
-use crate::utils::build_env;
+mod triple;
+pub use triple::*;
+
+mod riscv;
+pub use riscv::*;
+
+mod rustc_cfg;
+pub use rustc_cfg::*;
+
+mod rustc_info;
+pub use rustc_info::*;
+
+mod rustflags;
+pub use rustflags::*;
+
+mod x86;
+pub use x86::*;
 
+pub mod targets;
+
+mod env_source;
+pub use env_source::*;
+
+mod error;
+pub use error::*;
+
+use crate::utils::{build_env, expect_env};
+
+mod json;
 mod utils;
 
+/// Gets the current target [`Abi`]. This function is equivalent to [`Abi::target()`].
+#[must_use]
+pub fn target_abi() -> Option<Abi> {
+    Abi::target()
+}
 /// Gets the current target [`Arch`]. This function is equivalent to [`Arch::target()`].
 #[must_use]
 pub fn target_arch() -> Arch {
     Arch::target()
 }
+/// Gets the current target [`AtomicSupport`]. This function is equivalent to [`AtomicSupport::target()`].
+#[must_use]
+pub fn target_atomic_support() -> AtomicSupport {
+    AtomicSupport::target()
+}
 /// Gets the current target [`Endian`]. This function is equivalent to [`Endian::target()`].
 #[must_use]
 pub fn target_endian() -> Endian {
@@ -98,6 +200,11 @@ pub fn target_os() -> Os {
 pub fn target_pointer_width() -> PointerWidth {
     PointerWidth::target()
 }
+/// Gets the current [`TargetFeatures`]. This function is equivalent to [`TargetFeatures::target()`].
+#[must_use]
+pub fn target_features() -> TargetFeatures {
+    TargetFeatures::target()
+}
 /// Gets the current target [`Vendor`]. This function is equivalent to [`Vendor::target()`].
 #[must_use]
 pub fn target_vendor() -> Vendor {
@@ -106,10 +213,33 @@ pub fn target_vendor() -> Vendor {
 /// Gets the current target triple.
 #[must_use]
 pub fn target_triple() -> String {
-    build_env("TARGET")
+    expect_env(try_target_triple())
+}
+/// Tries to get the current target triple.
+pub fn try_target_triple() -> Result<String, Error> {
+    build_env(&ProcessEnv, "TARGET")
 }
 /// Gets the current target information as a [`Target`]. This function is equivalent to [`Target::current()`].
 #[must_use]
 pub fn target() -> Target {
     Target::current()
 }
+/// Gets the current host triple.
+#[must_use]
+pub fn host_triple() -> String {
+    expect_env(try_host_triple())
+}
+/// Tries to get the current host triple.
+pub fn try_host_triple() -> Result<String, Error> {
+    build_env(&ProcessEnv, "HOST")
+}
+/// Gets the current host information as a [`Target`]. This function is equivalent to [`Target::host()`].
+#[must_use]
+pub fn host() -> Target {
+    Target::host()
+}
+/// Checks whether the current build is cross compiling, i.e. whether the host and target triple differ.
+#[must_use]
+pub fn is_cross_compiling() -> bool {
+    host_triple() != target_triple()
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use crate::utils::{build_env, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env, define_target_enum, expect_env},
+};
 
 define_target_enum! {
     // adapted from target/os.rs from platforms crate
@@ -34,6 +37,8 @@ define_target_enum! {
         Fuchsia => "fuchsia",
         /// Haiku, an open source BeOS clone
         Haiku => "haiku",
+        /// HelenOS, a portable microkernel-based multiserver OS
+        HelenOS => "helenos",
         /// Unikernel targeting HPC and cloud environments
         Hermit => "hermit",
         Horizon => "horizon",
@@ -51,6 +56,10 @@ define_target_enum! {
         Lynxos178 => "lynxos178",
         /// Apple’s Mac OS X
         MacOS => "macos",
+        /// Managarm, a microkernel-based OS with asynchronous I/O
+        Managarm => "managarm",
+        /// Motor OS, a Rust-first microkernel OS
+        Motor => "motor",
         /// The NetBSD operating system
         NetBSD => "netbsd",
         None => "none",
@@ -64,6 +73,8 @@ define_target_enum! {
         Psp => "psp",
         /// PlayStation OS
         Psx => "psx",
+        /// Qualcomm real-time OS for Hexagon DSPs
+        Qurt => "qurt",
         /// Redox, a Unix-like OS written in Rust
         Redox => "redox",
         /// Real-time executive OS
@@ -80,6 +91,8 @@ define_target_enum! {
         /// Firmware interface
         Uefi => "uefi",
         Unknown => "unknown",
+        /// VEX V5 robot brain runtime
+        VexOS => "vexos",
         /// Apple spatial OS
         VisionOS => "visionos",
         /// PlayStation Vita OS
@@ -106,7 +119,17 @@ impl Os {
     /// Gets the current target [`Os`].
     #[must_use]
     pub fn target() -> Self {
-        Self::from_str(build_env("CARGO_CFG_TARGET_OS"))
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`Os`].
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`Os`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "CARGO_CFG_TARGET_OS").map(Self::from_str)
     }
 }
 
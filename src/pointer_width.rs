@@ -1,6 +1,9 @@
 use std::{fmt, num::ParseIntError};
 
-use crate::utils::{build_env, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env, define_target_enum, expect_env},
+};
 
 define_target_enum! {
     /// The endianness of the target architecture.
@@ -23,7 +26,17 @@ impl PointerWidth {
     /// Gets the current target [`PointerWidth`].
     #[must_use]
     pub fn target() -> Self {
-        Self::from_str(build_env("CARGO_CFG_TARGET_POINTER_WIDTH"))
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`PointerWidth`].
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`PointerWidth`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "CARGO_CFG_TARGET_POINTER_WIDTH").map(Self::from_str)
     }
 }
 
@@ -1,6 +1,9 @@
-use std::fmt;
+use std::{fmt, path::Path};
 
-use crate::utils::{build_env, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env, build_env_flag, build_env_opt, define_target_enum, expect_env},
+};
 
 define_target_enum! {
     /// Profile of the current build.
@@ -16,9 +19,13 @@ define_target_enum! {
         Release => "release",
 
         /// The test profile is used for building tests, or when benchmarks are built in debug mode with `cargo build`.
+        ///
+        /// Build scripts cannot tell it apart from [`Profile::Dev`], so [`Profile::current()`] never returns it.
         Test => "test",
 
         /// The bench profile is used for building benchmarks, or when tests are built with the `--release` flag.
+        ///
+        /// Build scripts cannot tell it apart from [`Profile::Release`], so [`Profile::current()`] never returns it.
         Bench => "bench",
     }
 
@@ -28,9 +35,125 @@ define_target_enum! {
 
 impl Profile {
     /// Gets the current [`Profile`].
+    ///
+    /// # Note
+    /// Cargo does not tell build scripts which profile is used directly, so the profile is derived from the
+    /// layout of `OUT_DIR`, which contains the name of the output directory of the profile, e.g.
+    /// `target/profiling/build/<pkg>/out` for a custom `profiling` profile. As the built-in `test` and
+    /// `bench` profiles share their output directories with `dev` and `release`, they are reported as
+    /// [`Profile::Dev`] and [`Profile::Release`] respectively.
+    ///
+    /// If `OUT_DIR` does not follow this layout, the profile is derived from `PROFILE` and finally from
+    /// `OPT_LEVEL`, `DEBUG` and `CARGO_CFG_DEBUG_ASSERTIONS`.
     #[must_use]
     pub fn current() -> Self {
-        Self::from_str(build_env("PROFILE"))
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the current [`Profile`]. See [`Profile::current()`] for details.
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`Profile`] from the given [`EnvSource`]. See [`Profile::current()`] for details.
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        if let Some(profile) = build_env_opt(env, "OUT_DIR")?
+            .as_deref()
+            .and_then(|out_dir| Self::from_out_dir(Path::new(out_dir)))
+        {
+            return Ok(profile);
+        }
+
+        if build_env_opt(env, "PROFILE")?.is_some() {
+            return Self::base_from_env(env);
+        }
+
+        let debug_assertions = build_env_flag(env, "CARGO_CFG_DEBUG_ASSERTIONS");
+        let opt_level = build_env_opt(env, "OPT_LEVEL")?;
+        let debug = build_env_opt(env, "DEBUG")?;
+        match (opt_level.as_deref(), debug.as_deref()) {
+            // nothing to go on, report the missing `PROFILE` variable
+            (None, None) if !debug_assertions => Self::base_from_env(env),
+            (Some("0"), _) => Ok(Self::Dev),
+            (None, Some(debug)) if !matches!(debug, "false" | "0" | "none") => Ok(Self::Dev),
+            _ if debug_assertions => Ok(Self::Dev),
+            _ => Ok(Self::Release),
+        }
+    }
+
+    /// Gets the built-in [`Profile`] the current profile inherits from, i.e. either [`Profile::Dev`] or
+    /// [`Profile::Release`].
+    ///
+    /// This is based on the `PROFILE` variable, which Cargo sets to `debug` or `release`.
+    #[must_use]
+    pub fn current_base() -> Self {
+        expect_env(Self::try_current_base())
+    }
+
+    /// Tries to get the built-in [`Profile`] the current profile inherits from. See [`Profile::current_base()`].
+    pub fn try_current_base() -> Result<Self, Error> {
+        Self::base_from_env(&ProcessEnv)
+    }
+
+    /// Gets the built-in [`Profile`] the profile described by the given [`EnvSource`] inherits from.
+    /// See [`Profile::current_base()`].
+    pub fn base_from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        Self::raw_profile_from_env(env).map(|profile| match profile.as_str() {
+            "debug" => Self::Dev,
+            profile => Self::from_str(profile),
+        })
+    }
+
+    /// Gets the raw value of the `PROFILE` variable, i.e. `debug` or `release`.
+    ///
+    /// In contrast to [`Profile::current_base()`], `debug` is kept as is instead of being mapped to
+    /// [`Profile::Dev`].
+    #[must_use]
+    pub fn raw_profile() -> String {
+        expect_env(Self::try_raw_profile())
+    }
+
+    /// Tries to get the raw value of the `PROFILE` variable. See [`Profile::raw_profile()`].
+    pub fn try_raw_profile() -> Result<String, Error> {
+        Self::raw_profile_from_env(&ProcessEnv)
+    }
+
+    /// Gets the raw value of the `PROFILE` variable from the given [`EnvSource`]. See [`Profile::raw_profile()`].
+    pub fn raw_profile_from_env(env: &impl EnvSource) -> Result<String, Error> {
+        build_env(env, "PROFILE")
+    }
+
+    /// Derives the [`Profile`] from the path of an `OUT_DIR`, which follows the layout
+    /// `<target-dir>/[<triple>/]<profile-dir>/build/<pkg>-<hash>/out`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::path::Path;
+    /// use build_target::Profile;
+    ///
+    /// let out_dir = Path::new("target/x86_64-unknown-linux-gnu/profiling/build/foo-0123456789abcdef/out");
+    /// assert_eq!(Profile::from_out_dir(out_dir), Some(Profile::Other("profiling".to_string())));
+    /// assert_eq!(Profile::from_out_dir(Path::new("target/debug/build/foo-0123456789abcdef/out")), Some(Profile::Dev));
+    /// assert_eq!(Profile::from_out_dir(Path::new("/tmp/out")), None);
+    /// ```
+    #[must_use]
+    pub fn from_out_dir(out_dir: &Path) -> Option<Self> {
+        let mut components = out_dir.components().rev().map(|c| c.as_os_str().to_str());
+        let (Some(Some("out")), Some(Some(_package)), Some(Some("build")), Some(Some(profile))) = (
+            components.next(),
+            components.next(),
+            components.next(),
+            components.next(),
+        ) else {
+            return None;
+        };
+
+        Some(match profile {
+            "debug" => Self::Dev,
+            "release" => Self::Release,
+            // profile names are case-sensitive, so custom ones are kept as is
+            profile => Self::Other(profile.to_string()),
+        })
     }
 }
 
@@ -39,3 +162,68 @@ impl fmt::Display for Profile {
         f.write_str(self.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn custom_profile() {
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("PROFILE", "release"),
+            (
+                "OUT_DIR",
+                "/work/target/profiling/build/foo-0123456789abcdef/out",
+            ),
+        ]);
+        assert_eq!(
+            Profile::from_env(&env),
+            Ok(Profile::Other("profiling".to_string()))
+        );
+        assert_eq!(Profile::base_from_env(&env), Ok(Profile::Release));
+
+        let out_dir = Path::new("target/Profiling/build/foo-0123456789abcdef/out");
+        assert_eq!(
+            Profile::from_out_dir(out_dir),
+            Some(Profile::Other("Profiling".to_string()))
+        );
+        let out_dir = Path::new("target/release/build/foo-0123456789abcdef/out");
+        assert_eq!(Profile::from_out_dir(out_dir), Some(Profile::Release));
+    }
+
+    #[test]
+    fn fallback_to_profile_var() {
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("PROFILE", "debug"),
+            ("OUT_DIR", "/custom/out"),
+        ]);
+        assert_eq!(Profile::from_env(&env), Ok(Profile::Dev));
+        assert_eq!(Profile::raw_profile_from_env(&env), Ok("debug".to_string()));
+    }
+
+    #[test]
+    fn fallback_to_settings() {
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("OPT_LEVEL", "3"),
+            ("DEBUG", "false"),
+        ]);
+        assert_eq!(Profile::from_env(&env), Ok(Profile::Release));
+
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("OPT_LEVEL", "1"),
+            ("CARGO_CFG_DEBUG_ASSERTIONS", ""),
+        ]);
+        assert_eq!(Profile::from_env(&env), Ok(Profile::Dev));
+
+        let env = HashMap::from([("TARGET", "x86_64-unknown-linux-gnu")]);
+        assert_eq!(
+            Profile::from_env(&env),
+            Err(Error::MissingVariables(vec!["PROFILE".to_string()]))
+        );
+    }
+}
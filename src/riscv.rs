@@ -0,0 +1,382 @@
+use std::fmt;
+
+use crate::{
+    Arch, EnvSource, Error, ProcessEnv, SubArch, Target, TargetFeature, TargetFeatures, Triple,
+    utils::{build_env, expect_env},
+};
+
+/// Canonical order of the single-letter extensions in an ISA string.
+const SINGLE_LETTER_ORDER: &str = "mafdqlcbjtpvh";
+
+/// Canonical order of the categories of `z` extensions, given by their second letter.
+const CANONICAL_ORDER: &str = "iemafdqlcbkjtpvh";
+
+/// Extensions of the `G` shorthand besides the base ISA.
+const GENERAL: &[&str] = &["m", "a", "f", "d", "zicsr", "zifencei"];
+
+/// Mandatory extensions of the RVA23U64 profile besides the base ISA.
+const RVA23U64: &[&str] = &[
+    "rva23u64",
+    "m",
+    "a",
+    "f",
+    "d",
+    "c",
+    "b",
+    "v",
+    "zicsr",
+    "zifencei",
+    "zicntr",
+    "zihpm",
+    "ziccif",
+    "ziccrse",
+    "ziccamoa",
+    "zicclsm",
+    "za64rs",
+    "zihintpause",
+    "zic64b",
+    "zicbom",
+    "zicbop",
+    "zicboz",
+    "zfhmin",
+    "zkt",
+    "zvfhmin",
+    "zvbb",
+    "zvkt",
+    "zihintntl",
+    "zicond",
+    "zimop",
+    "zcmop",
+    "zcb",
+    "zfa",
+    "zawrs",
+    "zba",
+    "zbb",
+    "zbs",
+];
+
+/// Instruction set of a RISC-V target, i.e. the base ISA and the enabled extensions.
+///
+/// # Example
+/// ```rust
+/// use build_target::{RiscvIsa, Target};
+///
+/// let isa = RiscvIsa::parse("riscv32imafc").unwrap();
+/// assert_eq!(isa.xlen, 32);
+/// assert!(isa.has_extension("f"));
+/// assert!(!isa.has_extension("d"));
+/// assert_eq!(isa.to_string(), "rv32imafc");
+/// assert_eq!(isa.abi, "ilp32f");
+///
+/// let target = Target::lookup("riscv64gc-unknown-linux-gnu").unwrap();
+/// let isa = target.riscv_isa().unwrap();
+/// assert_eq!(isa.to_string(), "rv64imafdc_zicsr_zifencei_zaamo_zalrsc_zca");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RiscvIsa {
+    /// The width of the integer registers in bits, i.e. `32` or `64`.
+    pub xlen: u8,
+    /// Whether the reduced base ISA for embedded systems (`E`) is used instead of the full one (`I`).
+    pub embedded: bool,
+    /// The enabled extensions, named like the corresponding target features, e.g. `m`, `c` or `zba`.
+    pub extensions: TargetFeatures,
+    /// The calling convention as passed to `-mabi`, such as `ilp32`, `ilp32f` or `lp64d`.
+    ///
+    /// Floating point arguments are passed in registers of the widest floating point extension of the base ISA,
+    /// which is what the built-in rustc targets use. For targets, this is derived from the ISA in the triple only,
+    /// as extensions enabled through `-C target-feature` do not change the calling convention of a target.
+    pub abi: &'static str,
+}
+
+impl RiscvIsa {
+    /// Gets the [`RiscvIsa`] of the current target or [`None`] if it is not a RISC-V target.
+    #[must_use]
+    pub fn target() -> Option<Self> {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the [`RiscvIsa`] of the current target.
+    pub fn try_target() -> Result<Option<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`RiscvIsa`] of the target described by the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Option<Self>, Error> {
+        let arch = Arch::from_env(env)?;
+        let triple = build_env(env, "TARGET")?;
+        let features = TargetFeatures::from_env(env)?;
+        Ok(Self::from_parts(&arch, &triple, &features))
+    }
+
+    /// Gets the [`RiscvIsa`] of the given target or [`None`] if it is not a RISC-V target.
+    ///
+    /// The extensions are taken from both the triple (e.g. `imac` in `riscv32imac`) and the enabled target
+    /// features, as stable compilers do not report all extensions as target features.
+    #[must_use]
+    pub fn from_target(target: &Target) -> Option<Self> {
+        Self::from_parts(&target.arch, &target.triple, &target.features)
+    }
+
+    fn from_parts(arch: &Arch, triple: &str, features: &TargetFeatures) -> Option<Self> {
+        let xlen = match arch {
+            Arch::Riscv32 => 32,
+            Arch::Riscv64 => 64,
+            _ => return None,
+        };
+
+        let mut isa = Triple::parse(triple)
+            .ok()
+            .and_then(|triple| Self::parse(&triple.arch))
+            .filter(|isa| isa.xlen == xlen)
+            .unwrap_or(Self {
+                xlen,
+                embedded: false,
+                extensions: TargetFeatures::new(),
+                abi: "",
+            });
+        for feature in features.for_arch(arch) {
+            if *feature == TargetFeature::E {
+                isa.embedded = true;
+            } else if *feature != TargetFeature::CrtStatic {
+                isa.extensions.insert(feature.clone());
+            }
+        }
+        // targets without an ISA in their triple only have their features to go by
+        if isa.abi.is_empty() {
+            isa.abi = isa.default_abi();
+        }
+        Some(isa)
+    }
+
+    /// Parses an ISA string such as `rv64gc`, `rv32imac_zba_zbb` or the architecture component of a triple
+    /// such as `riscv32imac`. The `G` shorthand and the `a23` profile of `riscv64a23` are expanded.
+    #[must_use]
+    pub fn parse(isa: &str) -> Option<Self> {
+        let isa = isa.to_ascii_lowercase();
+        let rest = isa
+            .strip_prefix("riscv")
+            .or_else(|| isa.strip_prefix("rv"))?;
+        let (xlen, rest) = if let Some(rest) = rest.strip_prefix("32") {
+            (32, rest)
+        } else {
+            (64, rest.strip_prefix("64")?)
+        };
+
+        let mut parts = rest.split('_');
+        let letters = parts.next().unwrap_or_default();
+        let mut extensions = TargetFeatures::new();
+        let (embedded, letters) = match letters.chars().next() {
+            Some('e') => (true, &letters[1..]),
+            Some('i') => (false, &letters[1..]),
+            Some('g') => {
+                extensions.extend(GENERAL.iter().copied().map(TargetFeature::from_str));
+                (false, &letters[1..])
+            }
+            // `riscv64a23` denotes the RVA23U64 profile
+            Some('a') if letters.starts_with("a23") => {
+                extensions.extend(RVA23U64.iter().copied().map(TargetFeature::from_str));
+                (false, &letters[3..])
+            }
+            // e.g. `riscv32` without any extensions
+            None => (false, letters),
+            Some(_) => return None,
+        };
+
+        for letter in letters.chars() {
+            if letter == 'g' {
+                extensions.extend(GENERAL.iter().copied().map(TargetFeature::from_str));
+            } else if letter.is_ascii_lowercase() {
+                extensions.insert(TargetFeature::from_str(letter.to_string()));
+            } else {
+                return None;
+            }
+        }
+        extensions.extend(
+            parts
+                .filter(|part| !part.is_empty())
+                .map(TargetFeature::from_str),
+        );
+
+        let mut isa = Self {
+            xlen,
+            embedded,
+            extensions,
+            abi: "",
+        };
+        isa.abi = isa.default_abi();
+        Some(isa)
+    }
+
+    /// Gets the base ISA as a [`SubArch`], i.e. [`SubArch::Rv32i`], [`SubArch::Rv32e`] or [`SubArch::Rv64i`].
+    #[must_use]
+    pub fn base(&self) -> SubArch {
+        match (self.xlen, self.embedded) {
+            (32, true) => SubArch::Rv32e,
+            (32, false) => SubArch::Rv32i,
+            (xlen, true) => SubArch::Other(format!("rv{xlen}e")),
+            _ => SubArch::Rv64i,
+        }
+    }
+
+    /// Checks whether the extension with the given name, such as `m` or `zba`, is enabled.
+    #[must_use]
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.contains(&TargetFeature::from_str(name))
+    }
+
+    /// Checks whether integer multiplication and division (`M`) is supported.
+    #[must_use]
+    pub fn has_mul_div(&self) -> bool {
+        self.has_extension("m")
+    }
+
+    /// Checks whether atomic read-modify-write operations are supported natively (`A` or `Zaamo`), i.e. whether
+    /// atomics beyond loads and stores have to be emulated otherwise.
+    #[must_use]
+    pub fn has_atomics(&self) -> bool {
+        self.has_extension("a") || self.has_extension("zaamo")
+    }
+
+    /// Checks whether single precision floating point instructions are supported (`F`).
+    #[must_use]
+    pub fn has_single_float(&self) -> bool {
+        self.has_extension("f")
+    }
+
+    /// Checks whether double precision floating point instructions are supported (`D`).
+    #[must_use]
+    pub fn has_double_float(&self) -> bool {
+        self.has_extension("d")
+    }
+
+    /// Checks whether compressed instructions are supported (`C` or `Zca`).
+    #[must_use]
+    pub fn has_compressed(&self) -> bool {
+        self.has_extension("c") || self.has_extension("zca")
+    }
+
+    /// Checks whether vector instructions are supported (`V`).
+    #[must_use]
+    pub fn has_vector(&self) -> bool {
+        self.has_extension("v")
+    }
+
+    /// Gets the calling convention matching the extensions of this ISA.
+    fn default_abi(&self) -> &'static str {
+        match (self.xlen, self.embedded) {
+            (32, true) => "ilp32e",
+            (32, false) if self.has_double_float() => "ilp32d",
+            (32, false) if self.has_single_float() => "ilp32f",
+            (32, false) => "ilp32",
+            (_, true) => "lp64e",
+            _ if self.has_double_float() => "lp64d",
+            _ if self.has_single_float() => "lp64f",
+            _ => "lp64",
+        }
+    }
+}
+
+impl fmt::Display for RiscvIsa {
+    /// Formats the ISA as a canonical ISA string as passed to `-march`, such as `rv64imafdc_zicsr_zifencei`.
+    ///
+    /// Multi-letter extensions are ordered by their prefix (`z`, `s`, then `x`), `z` extensions by the category
+    /// given by their second letter in single-letter order, and extensions of the same category alphabetically.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rv{}{}",
+            self.xlen,
+            if self.embedded { 'e' } else { 'i' }
+        )?;
+        for letter in SINGLE_LETTER_ORDER.chars() {
+            if self.has_extension(&letter.to_string()) {
+                write!(f, "{letter}")?;
+            }
+        }
+        let mut extensions = self
+            .extensions
+            .iter()
+            .map(TargetFeature::as_str)
+            .filter(|extension| extension.len() > 1 && extension.starts_with(['z', 's', 'x']))
+            .collect::<Vec<_>>();
+        extensions.sort_by_key(|extension| {
+            let prefix = "zsx".find(&extension[..1]);
+            let category = extension
+                .strip_prefix('z')
+                .and_then(|rest| rest.chars().next())
+                .and_then(|letter| CANONICAL_ORDER.find(letter));
+            (
+                prefix,
+                category.unwrap_or(CANONICAL_ORDER.len()),
+                *extension,
+            )
+        });
+        for extension in extensions {
+            write!(f, "_{extension}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Target {
+    /// Gets the [`RiscvIsa`] of this target or [`None`] if it is not a RISC-V target.
+    /// See [`RiscvIsa::from_target()`].
+    #[must_use]
+    pub fn riscv_isa(&self) -> Option<RiscvIsa> {
+        RiscvIsa::from_target(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let isa = RiscvIsa::parse("riscv64gc").unwrap();
+        assert_eq!(isa.xlen, 64);
+        assert_eq!(isa.base(), SubArch::Rv64i);
+        assert_eq!(isa.to_string(), "rv64imafdc_zicsr_zifencei");
+        assert_eq!(isa.abi, "lp64d");
+
+        let isa = RiscvIsa::parse("riscv32emc").unwrap();
+        assert_eq!(isa.base(), SubArch::Rv32e);
+        assert!(isa.has_mul_div() && isa.has_compressed() && !isa.has_atomics());
+        assert_eq!(isa.abi, "ilp32e");
+
+        let isa = RiscvIsa::parse("rv32imac_zba_zbb").unwrap();
+        assert_eq!(isa.to_string(), "rv32imac_zba_zbb");
+        assert_eq!(isa.abi, "ilp32");
+
+        let isa = RiscvIsa::parse("riscv64a23").unwrap();
+        assert!(isa.has_vector() && isa.has_double_float());
+        assert!(isa.has_extension("rva23u64"));
+
+        assert_eq!(RiscvIsa::parse("riscv32"), RiscvIsa::parse("rv32i"));
+        assert_eq!(RiscvIsa::parse("x86_64"), None);
+        assert_eq!(RiscvIsa::parse("riscv128i"), None);
+    }
+
+    #[test]
+    fn from_target() {
+        let target = Target::lookup("riscv32imafc-unknown-none-elf").unwrap();
+        let isa = target.riscv_isa().unwrap();
+        assert_eq!(isa.abi, "ilp32f");
+        assert!(isa.has_extension("zaamo"));
+
+        // enabling extensions does not change the calling convention of a target
+        let mut target = Target::lookup("riscv32imac-unknown-none-elf")
+            .unwrap()
+            .clone();
+        target.features.insert(TargetFeature::F);
+        let isa = target.riscv_isa().unwrap();
+        assert!(isa.has_single_float());
+        assert_eq!(isa.abi, "ilp32");
+
+        let target = Target::lookup("riscv32e-unknown-none-elf").unwrap();
+        assert_eq!(target.riscv_isa().unwrap().base(), SubArch::Rv32e);
+
+        let target = Target::lookup("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(target.riscv_isa(), None);
+    }
+}
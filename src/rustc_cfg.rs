@@ -0,0 +1,208 @@
+use std::{collections::BTreeMap, ffi::OsString, process::Command};
+
+use crate::{EnvOverride, EnvSource, Error, ProcessEnv, Target, utils::build_env_opt};
+
+/// Parsed output of `rustc --print cfg`, i.e. the cfgs rustc enables for a target.
+///
+/// The output consists of one cfg per line, either a bare name such as `unix` or a key value pair such as
+/// `target_arch="x86_64"`. Keys like `target_family` or `target_feature` can appear multiple times.
+///
+/// [`RustcCfg`] is an [`EnvSource`] which exposes the cfgs the same way Cargo does for build scripts,
+/// e.g. `target_feature="sse"` and `target_feature="sse2"` as `CARGO_CFG_TARGET_FEATURE=sse,sse2`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RustcCfg {
+    cfgs: BTreeMap<String, Vec<String>>,
+}
+
+impl RustcCfg {
+    /// Parses the given output of `rustc --print cfg`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use build_target::RustcCfg;
+    ///
+    /// let cfg = RustcCfg::parse("target_arch=\"x86_64\"\ntarget_feature=\"sse\"\ntarget_feature=\"sse2\"\nunix").unwrap();
+    /// assert_eq!(cfg.get("target_arch"), Some(&["x86_64".to_string()][..]));
+    /// assert_eq!(cfg.get("target_feature").map(<[_]>::len), Some(2));
+    /// assert!(cfg.contains("unix"));
+    /// ```
+    pub fn parse(output: &str) -> Result<Self, Error> {
+        let mut cfgs = BTreeMap::<String, Vec<String>>::new();
+
+        for line in output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let invalid = || Error::InvalidRustcCfg {
+                line: line.to_string(),
+            };
+
+            let (name, value) = match line.split_once('=') {
+                Some((name, value)) => {
+                    let value = value
+                        .strip_prefix('"')
+                        .and_then(|value| value.strip_suffix('"'))
+                        .ok_or_else(invalid)?;
+                    (name, Some(value))
+                }
+                None => (line, None),
+            };
+            if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+                return Err(invalid());
+            }
+
+            let values = cfgs.entry(name.to_string()).or_default();
+            values.extend(value.filter(|value| !value.is_empty()).map(str::to_string));
+        }
+
+        Ok(Self { cfgs })
+    }
+
+    /// Runs `rustc --print cfg --target <triple>` and parses its output.
+    ///
+    /// The compiler is taken from the `RUSTC` variable Cargo sets for build scripts, falling back to `rustc`
+    /// from the `PATH` if it is not set.
+    pub fn from_rustc(triple: &str) -> Result<Self, Error> {
+        let rustc = build_env_opt(&ProcessEnv, "RUSTC")?.unwrap_or_else(|| "rustc".to_string());
+        let output = Command::new(&rustc)
+            .args(["--print", "cfg", "--target", triple])
+            .output()
+            .map_err(|err| Error::Rustc(format!("failed to run {rustc}: {err}")))?;
+        if !output.status.success() {
+            return Err(Error::Rustc(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Gets all values of the cfg with the given name, which are empty for bare names like `unix`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&[String]> {
+        self.cfgs.get(name).map(Vec::as_slice)
+    }
+
+    /// Checks whether the cfg with the given name is set.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.cfgs.contains_key(name)
+    }
+
+    /// Iterates over the names of all set cfgs.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.cfgs.keys().map(String::as_str)
+    }
+}
+
+impl EnvSource for RustcCfg {
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        let key = name.strip_prefix("CARGO_CFG_")?.to_ascii_lowercase();
+        self.get(&key).map(|values| values.join(",").into())
+    }
+
+    fn var_names(&self) -> Vec<String> {
+        self.names()
+            .map(|name| format!("CARGO_CFG_{}", name.to_ascii_uppercase()))
+            .collect()
+    }
+}
+
+impl Target {
+    /// Derives a [`Target`] from the output of `rustc --print cfg --target <triple>`.
+    ///
+    /// As the output does not include the target triple itself, it has to be passed separately.
+    ///
+    /// # Example
+    /// ```rust
+    /// use build_target::{Arch, Family, Target, TargetFeature};
+    ///
+    /// let cfg = r#"
+    /// target_arch="x86_64"
+    /// target_endian="little"
+    /// target_env="gnu"
+    /// target_family="unix"
+    /// target_feature="fxsr"
+    /// target_feature="sse"
+    /// target_feature="sse2"
+    /// target_os="linux"
+    /// target_pointer_width="64"
+    /// target_vendor="unknown"
+    /// unix
+    /// "#;
+    ///
+    /// let target = Target::from_rustc_cfg("x86_64-unknown-linux-gnu", cfg).unwrap();
+    /// assert_eq!(target.arch, Arch::X86_64);
+    /// assert_eq!(target.family, vec![Family::Unix]);
+    /// assert!(target.features.contains(&TargetFeature::Sse2));
+    /// ```
+    pub fn from_rustc_cfg(triple: &str, cfg: &str) -> Result<Self, Error> {
+        Self::from_env(&EnvOverride::new(RustcCfg::parse(cfg)?).set("TARGET", triple))
+    }
+
+    /// Derives a [`Target`] for the given triple by querying rustc, see [`RustcCfg::from_rustc()`].
+    ///
+    /// This works for every target the compiler knows about, including ones missing from the
+    /// [embedded target database](crate::targets).
+    pub fn from_rustc(triple: &str) -> Result<Self, Error> {
+        Self::from_env(&EnvOverride::new(RustcCfg::from_rustc(triple)?).set("TARGET", triple))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Abi, AtomicWidth, Env, Os};
+
+    const THUMBV7EM: &str = r#"debug_assertions
+panic="abort"
+target_abi="eabihf"
+target_arch="arm"
+target_endian="little"
+target_env=""
+target_has_atomic="16"
+target_has_atomic="32"
+target_has_atomic="8"
+target_has_atomic="ptr"
+target_os="none"
+target_pointer_width="32"
+target_vendor="unknown"
+"#;
+
+    #[test]
+    fn parse() {
+        let cfg = RustcCfg::parse(THUMBV7EM).unwrap();
+        assert!(cfg.contains("debug_assertions"));
+        assert_eq!(cfg.get("debug_assertions"), Some(&[][..]));
+        assert_eq!(cfg.get("target_env"), Some(&[][..]));
+        assert_eq!(cfg.get("target_has_atomic").map(<[_]>::len), Some(4));
+        assert_eq!(
+            cfg.var_os("CARGO_CFG_TARGET_HAS_ATOMIC"),
+            Some("16,32,8,ptr".into())
+        );
+        assert_eq!(cfg.var_os("CARGO_CFG_TARGET_FAMILY"), None);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            RustcCfg::parse("target_arch=x86_64"),
+            Err(Error::InvalidRustcCfg {
+                line: "target_arch=x86_64".to_string()
+            })
+        );
+        assert!(RustcCfg::parse("not a cfg").is_err());
+    }
+
+    #[test]
+    fn target() {
+        let target = Target::from_rustc_cfg("thumbv7em-none-eabihf", THUMBV7EM).unwrap();
+        assert_eq!(target.env, None::<Env>);
+        assert_eq!(target.abi, Some(Abi::EabiHf));
+        assert_eq!(target.os, Os::None);
+        assert_eq!(target.atomics.max_atomic_width(), Some(32));
+        assert!(target.atomics.has_atomic(&AtomicWidth::Ptr));
+        assert_eq!(Some(&target), Target::lookup("thumbv7em-none-eabihf"));
+    }
+}
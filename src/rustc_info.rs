@@ -0,0 +1,272 @@
+use std::{fmt, process::Command, str::FromStr, sync::OnceLock};
+
+use crate::{
+    Error, ProcessEnv,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
+
+/// A version number such as `1.95.0` as reported for rustc and LLVM.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Version {
+    /// The major version, e.g. `1` for `1.95.0`.
+    pub major: u64,
+    /// The minor version, e.g. `95` for `1.95.0`.
+    pub minor: u64,
+    /// The patch version, e.g. `0` for `1.95.0`.
+    pub patch: u64,
+}
+
+impl Version {
+    /// Creates a new [`Version`] from its components.
+    #[must_use]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version such as `1.95.0`, `1.96.0-nightly` or `22.1`, ignoring any pre-release suffix.
+    /// Missing minor and patch versions are treated as `0`.
+    #[must_use]
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.split(['-', '+']).next()?;
+        let mut parts = version.split('.').map(str::parse);
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+        let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| Error::InvalidVersion(s.to_string()))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+define_target_enum! {
+    /// Release channel of a rustc build.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum Channel {
+        /// Stable releases published every six weeks.
+        Stable => "stable",
+        /// Beta releases of the next stable version.
+        Beta => "beta",
+        /// Nightly builds which allow the use of unstable features.
+        Nightly => "nightly",
+        /// Local builds of the compiler.
+        Dev => "dev",
+    }
+
+    as_str_doc = "String representing this release channel.",
+    from_str_doc = "Tries to parse the given string as a [`Channel`] falling back to [`Channel::Other`] for unknown values.",
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Version information about the compiler used for the build, as reported by `rustc -vV`.
+///
+/// # Example
+/// ```rust no_run
+/// // inside build.rs
+///
+/// use build_target::{RustcInfo, Version};
+///
+/// fn main() {
+///     if RustcInfo::current().version >= Version::new(1, 80, 0) {
+///         println!("cargo:rustc-cfg=has_lazy_cell");
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RustcInfo {
+    /// The version of the compiler, e.g. `1.95.0`.
+    pub version: Version,
+    /// The release channel of the compiler.
+    pub channel: Channel,
+    /// The hash of the commit the compiler was built from, if known.
+    pub commit_hash: Option<String>,
+    /// The date of the commit the compiler was built from, if known.
+    pub commit_date: Option<String>,
+    /// The version of LLVM the compiler uses, if it uses LLVM at all.
+    pub llvm_version: Option<Version>,
+    /// The triple of the platform the compiler runs on.
+    pub host: String,
+}
+
+impl RustcInfo {
+    /// Gets the [`RustcInfo`] of the compiler used for the current build.
+    ///
+    /// The compiler is only run on the first call, later calls return the same instance.
+    #[must_use]
+    pub fn current() -> &'static Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the [`RustcInfo`] of the compiler used for the current build. See [`RustcInfo::current()`].
+    ///
+    /// The compiler is taken from the `RUSTC` variable Cargo sets for build scripts, falling back to `rustc`
+    /// from the `PATH` if it is not set.
+    pub fn try_current() -> Result<&'static Self, Error> {
+        static CURRENT: OnceLock<Result<RustcInfo, Error>> = OnceLock::new();
+        CURRENT
+            .get_or_init(|| {
+                let rustc =
+                    build_env_opt(&ProcessEnv, "RUSTC")?.unwrap_or_else(|| "rustc".to_string());
+                Self::from_rustc(&rustc)
+            })
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Runs `<rustc> -vV` and parses its output.
+    pub fn from_rustc(rustc: &str) -> Result<Self, Error> {
+        let output = Command::new(rustc)
+            .arg("-vV")
+            .output()
+            .map_err(|err| Error::Rustc(format!("failed to run {rustc}: {err}")))?;
+        if !output.status.success() {
+            return Err(Error::Rustc(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Parses the given output of `rustc -vV`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use build_target::{Channel, RustcInfo, Version};
+    ///
+    /// let info = RustcInfo::parse("rustc 1.96.0-nightly (0123456789 2026-04-01)\nbinary: rustc\nhost: x86_64-unknown-linux-gnu\nrelease: 1.96.0-nightly\nLLVM version: 22.1.2").unwrap();
+    /// assert_eq!(info.version, Version::new(1, 96, 0));
+    /// assert_eq!(info.channel, Channel::Nightly);
+    /// assert_eq!(info.llvm_version, Some(Version::new(22, 1, 2)));
+    /// ```
+    pub fn parse(output: &str) -> Result<Self, Error> {
+        let mut release = None;
+        let mut commit_hash = None;
+        let mut commit_date = None;
+        let mut llvm_version = None;
+        let mut host = None;
+        for line in output.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            let value = (!value.is_empty() && value != "unknown").then_some(value);
+            match key.trim() {
+                "release" => release = value,
+                "commit-hash" => commit_hash = value.map(str::to_string),
+                "commit-date" => commit_date = value.map(str::to_string),
+                "LLVM version" => llvm_version = value.and_then(Version::parse),
+                "host" => host = value.map(str::to_string),
+                _ => {}
+            }
+        }
+
+        let release = release
+            .ok_or_else(|| Error::Rustc("missing release in output of rustc -vV".to_string()))?;
+        let channel = match release.split_once('-') {
+            None => Channel::Stable,
+            Some((_, suffix)) => Channel::from_str(suffix.split('.').next().unwrap_or(suffix)),
+        };
+
+        Ok(Self {
+            version: release.parse()?,
+            channel,
+            commit_hash,
+            commit_date,
+            llvm_version,
+            host: host
+                .ok_or_else(|| Error::Rustc("missing host in output of rustc -vV".to_string()))?,
+        })
+    }
+
+    /// Checks whether the compiler allows the use of unstable features, i.e. is a nightly or dev build.
+    ///
+    /// # Note
+    /// This does not account for `RUSTC_BOOTSTRAP`, which enables unstable features on stable compilers.
+    #[must_use]
+    pub fn is_nightly(&self) -> bool {
+        matches!(self.channel, Channel::Nightly | Channel::Dev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable() {
+        let info = RustcInfo::parse(
+            "rustc 1.95.0 (59807616e 2026-04-14)
+binary: rustc
+commit-hash: 59807616e1fa2540724bfbac14d7976d7e4a3860
+commit-date: 2026-04-14
+host: x86_64-unknown-linux-gnu
+release: 1.95.0
+LLVM version: 22.1.2",
+        )
+        .unwrap();
+        assert_eq!(
+            info,
+            RustcInfo {
+                version: Version::new(1, 95, 0),
+                channel: Channel::Stable,
+                commit_hash: Some("59807616e1fa2540724bfbac14d7976d7e4a3860".to_string()),
+                commit_date: Some("2026-04-14".to_string()),
+                llvm_version: Some(Version::new(22, 1, 2)),
+                host: "x86_64-unknown-linux-gnu".to_string(),
+            }
+        );
+        assert!(!info.is_nightly());
+    }
+
+    #[test]
+    fn beta_without_llvm() {
+        let info = RustcInfo::parse(
+            "rustc 1.96.0-beta.3
+binary: rustc
+commit-hash: unknown
+commit-date: unknown
+host: aarch64-apple-darwin
+release: 1.96.0-beta.3",
+        )
+        .unwrap();
+        assert_eq!(info.version, Version::new(1, 96, 0));
+        assert_eq!(info.channel, Channel::Beta);
+        assert_eq!(info.commit_hash, None);
+        assert_eq!(info.llvm_version, None);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(RustcInfo::parse("rustc 1.95.0").is_err());
+        assert_eq!(Version::parse("1.95"), Some(Version::new(1, 95, 0)));
+        assert_eq!(Version::parse("1.x.0"), None);
+        let err = "1.x.0".parse::<Version>().unwrap_err();
+        assert_eq!(err, Error::InvalidVersion("1.x.0".to_string()));
+        assert_eq!(err.to_string(), "invalid version `1.x.0`");
+    }
+}
@@ -0,0 +1,272 @@
+use std::{fmt, path::PathBuf};
+
+use crate::{
+    EnvSource, Error, ProcessEnv, TargetFeature, TargetFeatures,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// Relocation model of the generated code as set by `-C relocation-model`.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum RelocationModel {
+        /// The default relocation model of the target.
+        Default => "default",
+        /// Non-relocatable code, which is the most efficient but requires a fixed load address.
+        Static => "static",
+        /// Fully relocatable position independent code.
+        Pic => "pic",
+        /// Position independent executable code.
+        Pie => "pie",
+        /// Relocatable external references, non-relocatable code (Apple only).
+        DynamicNoPic => "dynamic-no-pic",
+        /// Code and read-only data accessed relative to the program counter (Arm only).
+        Ropi => "ropi",
+        /// Read-write data accessed relative to a static base register (Arm only).
+        Rwpi => "rwpi",
+        /// Combination of [`RelocationModel::Ropi`] and [`RelocationModel::Rwpi`] (Arm only).
+        RopiRwpi => "ropi-rwpi",
+    }
+
+    as_str_doc = "String representing this relocation model which matches the value of `-C relocation-model`.",
+    from_str_doc = "Tries to parse the given string as a [`RelocationModel`] falling back to [`RelocationModel::Other`] for unknown values.",
+}
+
+impl fmt::Display for RelocationModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options of rustc which take a value that may be passed as a separate flag.
+const OPTIONS_WITH_VALUE: &[&str] = &[
+    "-A",
+    "-C",
+    "-D",
+    "-F",
+    "-L",
+    "-W",
+    "-Z",
+    "-l",
+    "-o",
+    "--cap-lints",
+    "--cfg",
+    "--check-cfg",
+    "--codegen",
+    "--crate-name",
+    "--crate-type",
+    "--edition",
+    "--emit",
+    "--error-format",
+    "--extern",
+    "--json",
+    "--out-dir",
+    "--print",
+    "--remap-path-prefix",
+    "--sysroot",
+    "--target",
+];
+
+/// Flags passed to rustc for the current build, i.e. the contents of `RUSTFLAGS` or `build.rustflags`.
+///
+/// # Note
+/// The enabled features of the target already include the features enabled through `-C target-feature` and
+/// `-C target-cpu`. [`RustFlags`] tells where they came from, e.g. whether `target-cpu=native` was requested
+/// or a feature was disabled explicitly.
+///
+/// # Example
+/// ```rust
+/// use build_target::{RustFlags, TargetFeature};
+///
+/// let flags = RustFlags::parse(["-Ctarget-cpu=native", "-C", "target-feature=+avx2,-fma", "--cfg", "tokio_unstable"]);
+/// assert!(flags.is_native_cpu());
+/// assert_eq!(flags.target_feature(&TargetFeature::Avx2), Some(true));
+/// assert_eq!(flags.target_feature(&TargetFeature::Fma), Some(false));
+/// assert_eq!(flags.cfgs, ["tokio_unstable"]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RustFlags {
+    /// All flags in the order they were passed.
+    pub flags: Vec<String>,
+    /// The CPU to generate code for (`-C target-cpu`), such as `native` or `x86-64-v3`.
+    pub target_cpu: Option<String>,
+    /// The features explicitly enabled through `-C target-feature=+<feature>`.
+    pub enabled_features: TargetFeatures,
+    /// The features explicitly disabled through `-C target-feature=-<feature>`.
+    pub disabled_features: TargetFeatures,
+    /// The relocation model (`-C relocation-model`).
+    pub relocation_model: Option<RelocationModel>,
+    /// The linker (`-C linker`).
+    pub linker: Option<PathBuf>,
+    /// The additional cfgs (`--cfg`), such as `tokio_unstable` or `feature="foo"`.
+    pub cfgs: Vec<String>,
+    /// The additional linker arguments (`-C link-arg` and `-C link-args`).
+    pub link_args: Vec<String>,
+}
+
+impl RustFlags {
+    /// Gets the [`RustFlags`] of the current build.
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the [`RustFlags`] of the current build.
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`RustFlags`] of the build described by the given [`EnvSource`] from `CARGO_ENCODED_RUSTFLAGS`.
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        Ok(Self::from_encoded(
+            &build_env_opt(env, "CARGO_ENCODED_RUSTFLAGS")?.unwrap_or_default(),
+        ))
+    }
+
+    /// Parses flags encoded like `CARGO_ENCODED_RUSTFLAGS`, i.e. separated by `\x1f`.
+    #[must_use]
+    pub fn from_encoded(encoded: &str) -> Self {
+        Self::parse(encoded.split('\x1f').filter(|flag| !flag.is_empty()))
+    }
+
+    /// Parses the given flags as passed to rustc.
+    pub fn parse<I>(flags: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let flags = flags
+            .into_iter()
+            .map(|flag| flag.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let mut result = Self::default();
+
+        let mut iter = flags.iter();
+        while let Some(flag) = iter.next() {
+            let (option, value) = match flag.split_once('=') {
+                Some((option, value)) if option.starts_with("--") => (option, Some(value)),
+                // short options with an attached value such as `-Ctarget-cpu=native`
+                _ if !flag.starts_with("--") && flag.len() > 2 => match flag.split_at_checked(2) {
+                    Some((option, value)) => (option, Some(value)),
+                    None => (flag.as_str(), None),
+                },
+                _ => (flag.as_str(), None),
+            };
+            let value = match value {
+                Some(value) => value,
+                None if OPTIONS_WITH_VALUE.contains(&option) => match iter.next() {
+                    Some(value) => value.as_str(),
+                    None => break,
+                },
+                None => continue,
+            };
+
+            match option {
+                "-C" | "--codegen" => result.apply_codegen(value),
+                "--cfg" => result.cfgs.push(value.to_string()),
+                _ => {}
+            }
+        }
+
+        result.flags = flags;
+        result
+    }
+
+    fn apply_codegen(&mut self, option: &str) {
+        let (key, value) = option.split_once('=').unwrap_or((option, ""));
+        match key.replace('_', "-").as_str() {
+            "target-cpu" => self.target_cpu = Some(value.to_string()),
+            "target-feature" => {
+                for feature in value.split(',') {
+                    let (enabled, name) = match feature.split_at_checked(1) {
+                        Some(("+", name)) => (true, name),
+                        Some(("-", name)) => (false, name),
+                        _ => continue,
+                    };
+                    let feature = TargetFeature::from_str(name);
+                    if enabled {
+                        self.disabled_features.remove(&feature);
+                        self.enabled_features.insert(feature);
+                    } else {
+                        self.enabled_features.remove(&feature);
+                        self.disabled_features.insert(feature);
+                    }
+                }
+            }
+            "relocation-model" => self.relocation_model = Some(RelocationModel::from_str(value)),
+            "linker" => self.linker = Some(value.into()),
+            "link-arg" => self.link_args.push(value.to_string()),
+            "link-args" => self
+                .link_args
+                .extend(value.split_whitespace().map(str::to_string)),
+            _ => {}
+        }
+    }
+
+    /// Checks whether code is generated for the CPU of the host (`-C target-cpu=native`).
+    #[must_use]
+    pub fn is_native_cpu(&self) -> bool {
+        self.target_cpu.as_deref() == Some("native")
+    }
+
+    /// Gets whether the given feature was explicitly enabled (`Some(true)`) or disabled (`Some(false)`)
+    /// through `-C target-feature`, or [`None`] if it was not mentioned.
+    #[must_use]
+    pub fn target_feature(&self, feature: &TargetFeature) -> Option<bool> {
+        if self.enabled_features.contains(feature) {
+            Some(true)
+        } else if self.disabled_features.contains(feature) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoded() {
+        let flags = RustFlags::from_encoded(
+            "-Dwarnings\x1f--codegen=target-cpu=x86-64-v3\x1f-C\x1flinker=clang\x1f-Crelocation-model=pie\x1f--cfg=tokio_unstable\x1f--cfg\x1ffeature=\"foo\"",
+        );
+        assert_eq!(flags.flags.len(), 8);
+        assert_eq!(flags.target_cpu.as_deref(), Some("x86-64-v3"));
+        assert!(!flags.is_native_cpu());
+        assert_eq!(flags.linker, Some(PathBuf::from("clang")));
+        assert_eq!(flags.relocation_model, Some(RelocationModel::Pie));
+        assert_eq!(flags.cfgs, ["tokio_unstable", "feature=\"foo\""]);
+    }
+
+    #[test]
+    fn later_features_win() {
+        let flags = RustFlags::parse([
+            "-Ctarget-feature=+avx2,+fma",
+            "-C",
+            "target-feature=-avx2,+crt-static",
+        ]);
+        assert_eq!(flags.target_feature(&TargetFeature::Avx2), Some(false));
+        assert_eq!(flags.target_feature(&TargetFeature::Fma), Some(true));
+        assert_eq!(flags.target_feature(&TargetFeature::CrtStatic), Some(true));
+        assert_eq!(flags.target_feature(&TargetFeature::Sse2), None);
+    }
+
+    #[test]
+    fn link_args() {
+        let flags = RustFlags::parse([
+            "-C",
+            "link-arg=-Wl,--as-needed",
+            "-Clink-args=-lfoo -lbar",
+            "-L",
+            "native=/usr/lib",
+        ]);
+        assert_eq!(flags.link_args, ["-Wl,--as-needed", "-lfoo", "-lbar"]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(RustFlags::from_encoded(""), RustFlags::default());
+    }
+}
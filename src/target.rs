@@ -1,4 +1,11 @@
-use crate::{Arch, Endian, Env, Family, Os, PointerWidth, Vendor, target_triple};
+use std::{error, fmt, str::FromStr};
+
+use crate::{
+    Abi, Arch, AtomicSupport, Endian, Env, EnvSource, Error, Family, Os, PointerWidth, ProcessEnv,
+    TargetFeatures, Vendor,
+    triple::Components,
+    utils::{build_env, expect_env},
+};
 
 /// Combined information about a build target.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -9,12 +16,18 @@ pub struct Target {
     pub endian: Endian,
     /// The environment of the target, such as `gnu`, `msvc`, or `none`.
     pub env: Option<Env>,
+    /// The ABI of the target, such as `eabihf`, `sim`, or `llvm`.
+    pub abi: Option<Abi>,
     /// The operating system of the target, such as `linux`, `windows`, or `macos`.
     pub os: Os,
     /// The pointer width of the target, such as `32` or `64`.
     pub pointer_width: PointerWidth,
     /// The family of the target, such as `unix`, `windows`, or `wasm`.
     pub family: Vec<Family>,
+    /// The enabled target features, such as `sse2`, `neon`, or `crt-static`.
+    pub features: TargetFeatures,
+    /// The atomic operations supported by the target.
+    pub atomics: AtomicSupport,
     /// The vendor of the target, such as `apple`, `unknown`, or `pc`.
     pub vendor: Vendor,
     /// The target triple, which is a string that uniquely identifies the target.
@@ -25,15 +38,158 @@ impl Target {
     /// Gets the current build target as a [`Target`].
     #[must_use]
     pub fn current() -> Self {
-        Self {
-            arch: Arch::target(),
-            endian: Endian::target(),
-            env: Env::target(),
-            os: Os::target(),
-            pointer_width: PointerWidth::target(),
-            family: Family::target(),
-            vendor: Vendor::target(),
-            triple: target_triple(),
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the current build target as a [`Target`].
+    ///
+    /// If multiple variables are missing, all of them are reported in a single [`Error::MissingVariables`].
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the build target described by the given [`EnvSource`] as a [`Target`].
+    ///
+    /// If multiple variables are missing, all of them are reported in a single [`Error::MissingVariables`].
+    pub fn from_env(source: &impl EnvSource) -> Result<Self, Error> {
+        let arch = Arch::from_env(source);
+        let endian = Endian::from_env(source);
+        let env = Env::from_env(source);
+        let abi = Abi::from_env(source);
+        let os = Os::from_env(source);
+        let pointer_width = PointerWidth::from_env(source);
+        let family = Family::from_env(source);
+        let features = TargetFeatures::from_env(source);
+        let atomics = AtomicSupport::from_env(source);
+        let vendor = Vendor::from_env(source);
+        let triple = build_env(source, "TARGET");
+
+        let error = [
+            arch.as_ref().err(),
+            endian.as_ref().err(),
+            env.as_ref().err(),
+            abi.as_ref().err(),
+            os.as_ref().err(),
+            pointer_width.as_ref().err(),
+            family.as_ref().err(),
+            features.as_ref().err(),
+            atomics.as_ref().err(),
+            vendor.as_ref().err(),
+            triple.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .reduce(Error::merge);
+        if let Some(error) = error {
+            return Err(error);
         }
+
+        Ok(Self {
+            arch: arch?,
+            endian: endian?,
+            env: env?,
+            abi: abi?,
+            os: os?,
+            pointer_width: pointer_width?,
+            family: family?,
+            features: features?,
+            atomics: atomics?,
+            vendor: vendor?,
+            triple: triple?,
+        })
+    }
+
+    /// Gets the host of the current build as a [`Target`], i.e. the platform the build script and compiler run on.
+    ///
+    /// Cargo only describes the host by its triple, so the information is taken from the embedded target
+    /// database and derived from the triple itself for targets unknown to it.
+    #[must_use]
+    pub fn host() -> Self {
+        expect_env(Self::try_host())
+    }
+
+    /// Tries to get the host of the current build as a [`Target`]. See [`Target::host()`] for details.
+    pub fn try_host() -> Result<Self, Error> {
+        Self::host_from_env(&ProcessEnv)
+    }
+
+    /// Gets the host of the build described by the given [`EnvSource`] as a [`Target`].
+    /// See [`Target::host()`] for details.
+    pub fn host_from_env(source: &impl EnvSource) -> Result<Self, Error> {
+        let triple = build_env(source, "HOST")?;
+        match Self::lookup(&triple) {
+            Some(target) => Ok(target.clone()),
+            None => Ok(Self::from_triple(&triple)?),
+        }
+    }
+
+    /// Derives a [`Target`] from the given target triple without reading any environment variables.
+    ///
+    /// The components of the triple are normalized the same way rustc does it, e.g. `i686` is parsed as
+    /// [`Arch::X86`], `thumbv7m` as [`Arch::Arm`], `darwin` as [`Os::MacOS`] and `gnueabihf` as [`Env::Gnu`].
+    /// As the enabled target features and atomic support cannot be derived from the triple, [`Target::features`]
+    /// and [`Target::atomics`] are left empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use build_target::{Arch, Env, Os, Target};
+    ///
+    /// let target = Target::from_triple("armv7-unknown-linux-gnueabihf").unwrap();
+    /// assert_eq!(target.arch, Arch::Arm);
+    /// assert_eq!(target.os, Os::Linux);
+    /// assert_eq!(target.env, Some(Env::Gnu));
+    /// ```
+    pub fn from_triple(triple: &str) -> Result<Self, ParseTargetError> {
+        Components::split(triple)
+            .map(|components| components.to_target(triple))
+            .ok_or_else(|| ParseTargetError {
+                triple: triple.to_string(),
+            })
+    }
+
+    /// Looks up the built-in rustc target with the given triple in the embedded target database.
+    /// This function is equivalent to [`targets::lookup()`](crate::targets::lookup).
+    ///
+    /// In contrast to [`Target::from_triple()`], this returns [`None`] for targets unknown to rustc.
+    #[must_use]
+    pub fn lookup(triple: &str) -> Option<&'static Self> {
+        crate::targets::lookup(triple)
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParseTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_triple(s)
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.triple)
+    }
+}
+
+/// Error returned when a string could not be parsed as a target triple.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseTargetError {
+    pub(crate) triple: String,
+}
+
+impl ParseTargetError {
+    /// The string that failed to parse.
+    #[must_use]
+    pub fn triple(&self) -> &str {
+        &self.triple
     }
 }
+
+impl fmt::Display for ParseTargetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid target triple `{}`", self.triple)
+    }
+}
+
+impl error::Error for ParseTargetError {}
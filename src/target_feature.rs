@@ -0,0 +1,1262 @@
+use std::{collections::BTreeSet, fmt};
+
+use crate::{
+    Arch, EnvSource, Error, ProcessEnv,
+    utils::{build_env_opt, define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// A feature of the target CPU or platform such as an instruction set extension.
+    ///
+    /// # Note
+    /// The variants mirror the features accepted by `#[cfg(target_feature)]` on the architectures rustc supports
+    /// them for. Parsing does not depend on the architecture, so a feature such as `neon` is parsed as its variant
+    /// for any target and only names unknown on all architectures become [`TargetFeature::Other`]. Use
+    /// [`TargetFeature::is_available_on()`] to check whether a feature belongs to a given [`Arch`].
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum TargetFeature {
+        /// Enables C Run-time Libraries to be statically linked.
+        CrtStatic => "crt-static",
+        /// LA32 Standard Basic Instruction Extension.
+        La32s => "32s",
+        /// 'A' (Atomic Instructions).
+        A => "a",
+        /// Is application profile ('A' series).
+        Aclass => "aclass",
+        /// Support ADX instructions.
+        Adx => "adx",
+        /// Enable AES instructions.
+        Aes => "aes",
+        /// Enable Altivec instructions.
+        Altivec => "altivec",
+        /// Support AMX-AVX512 instructions.
+        AmxAvx512 => "amx-avx512",
+        /// Support AMX-BF16 instructions.
+        AmxBf16 => "amx-bf16",
+        /// Support AMX-COMPLEX instructions.
+        AmxComplex => "amx-complex",
+        /// Support AMX amx-fp16 instructions.
+        AmxFp16 => "amx-fp16",
+        /// Support AMX-FP8 instructions.
+        AmxFp8 => "amx-fp8",
+        /// Support AMX-INT8 instructions.
+        AmxInt8 => "amx-int8",
+        /// Support AMX-MOVRS instructions.
+        AmxMovrs => "amx-movrs",
+        /// Support AMX-TF32 instructions.
+        AmxTf32 => "amx-tf32",
+        /// Support AMX-TILE instructions.
+        AmxTile => "amx-tile",
+        /// Support extended general purpose register.
+        Apxf => "apxf",
+        /// Enable Atomics.
+        Atomics => "atomics",
+        /// Enable AVX instructions.
+        Avx => "avx",
+        /// Support AVX10.1 instruction.
+        Avx10_1 => "avx10.1",
+        /// Support AVX10.2 instruction.
+        Avx10_2 => "avx10.2",
+        /// Enable AVX2 instructions.
+        Avx2 => "avx2",
+        /// Support bfloat16 floating point.
+        Avx512bf16 => "avx512bf16",
+        /// Enable AVX-512 Bit Algorithms.
+        Avx512bitalg => "avx512bitalg",
+        /// Enable AVX-512 Byte and Word Instructions.
+        Avx512bw => "avx512bw",
+        /// Enable AVX-512 Conflict Detection Instructions.
+        Avx512cd => "avx512cd",
+        /// Enable AVX-512 Doubleword and Quadword Instructions.
+        Avx512dq => "avx512dq",
+        /// Enable AVX-512 instructions.
+        Avx512f => "avx512f",
+        /// Support 16-bit floating point.
+        Avx512fp16 => "avx512fp16",
+        /// Enable AVX-512 Integer Fused Multiple-Add.
+        Avx512ifma => "avx512ifma",
+        /// Enable AVX-512 Vector Byte Manipulation Instructions.
+        Avx512vbmi => "avx512vbmi",
+        /// Enable AVX-512 further Vector Byte Manipulation Instructions.
+        Avx512vbmi2 => "avx512vbmi2",
+        /// Enable AVX-512 Vector Length eXtensions.
+        Avx512vl => "avx512vl",
+        /// Enable AVX-512 Vector Neural Network Instructions.
+        Avx512vnni => "avx512vnni",
+        /// Enable AVX-512 vp2intersect.
+        Avx512vp2intersect => "avx512vp2intersect",
+        /// Enable AVX-512 Population Count Instructions.
+        Avx512vpopcntdq => "avx512vpopcntdq",
+        /// Enable AVX-IFMA.
+        Avxifma => "avxifma",
+        /// Support AVX-NE-CONVERT instructions.
+        Avxneconvert => "avxneconvert",
+        /// Support AVX_VNNI encoding.
+        Avxvnni => "avxvnni",
+        /// Enable AVX-VNNI-INT16.
+        Avxvnniint16 => "avxvnniint16",
+        /// Enable AVX-VNNI-INT8.
+        Avxvnniint8 => "avxvnniint8",
+        /// 'B' (the collection of the Zba, Zbb, Zbs extensions).
+        B => "b",
+        /// Store the address of the caller's frame into the callee's stack frame.
+        Backchain => "backchain",
+        /// Enable BFloat16 Extension.
+        Bf16 => "bf16",
+        /// Support BMI instructions.
+        Bmi1 => "bmi1",
+        /// Support BMI2 instructions.
+        Bmi2 => "bmi2",
+        /// Enable Branch Target Identification.
+        Bti => "bti",
+        /// Enable bulk memory operations.
+        BulkMemory => "bulk-memory",
+        /// 'C' (Compressed Instructions).
+        C => "c",
+        /// 64-bit with cmpxchg16b (this is true for most x86-64 chips, but not the first AMD chips).
+        Cmpxchg16b => "cmpxchg16b",
+        /// Assume that the concurrent-functions facility is installed.
+        ConcurrentFunctions => "concurrent-functions",
+        /// Enable Armv8.0-A CRC-32 checksum instructions.
+        Crc => "crc",
+        /// Enable Common Short Sequence Compression (CSSC) instructions.
+        Cssc => "cssc",
+        /// 'D' (Double-Precision Floating-Point).
+        D => "d",
+        /// Extend FP to 32 double registers.
+        D32 => "d32",
+        /// Assume that the deflate-conversion facility is installed.
+        DeflateConversion => "deflate-conversion",
+        /// Enable Armv8.4-A Data Independent Timing instructions.
+        Dit => "dit",
+        /// Assume div.w\[u\] and mod.w\[u\] can handle inputs that are not sign-extended.
+        Div32 => "div32",
+        /// Enable dot product support.
+        Dotprod => "dotprod",
+        /// Enable Armv8.2-A data Cache Clean to Point of Persistence.
+        Dpb => "dpb",
+        /// Enable Armv8.5-A Cache Clean to Point of Deep Persistence.
+        Dpb2 => "dpb2",
+        /// Supports DSP instructions in ARM and/or Thumb2.
+        Dsp => "dsp",
+        /// 'E' (Embedded Instruction Set with 16 GPRs).
+        E => "e",
+        /// Enable enhanced counter virtualization extension.
+        Ecv => "ecv",
+        /// Assume that the enhanced-sort facility is installed.
+        EnhancedSort => "enhanced-sort",
+        /// REP MOVS/STOS are fast.
+        Ermsb => "ermsb",
+        /// Enable Wasm exception handling.
+        ExceptionHandling => "exception-handling",
+        /// Enable extended const expressions.
+        ExtendedConst => "extended-const",
+        /// 'F' (Single-Precision Floating-Point).
+        F => "f",
+        /// Support 16-bit floating point conversion instructions.
+        F16c => "f16c",
+        /// Enable Matrix Multiply FP32 Extension.
+        F32mm => "f32mm",
+        /// Enable Matrix Multiply FP64 Extension.
+        F64mm => "f64mm",
+        /// Enable FAMIN and FAMAX instructions.
+        Faminmax => "faminmax",
+        /// Enable Armv8.3-A Floating-point complex number support.
+        Fcma => "fcma",
+        /// Enable FP16 FML instructions.
+        Fhm => "fhm",
+        /// Enable Armv8.4-A Flag Manipulation instructions.
+        Flagm => "flagm",
+        /// Enable alternative NZCV format for floating point comparisons.
+        Flagm2 => "flagm2",
+        /// Enable three-operand fused multiple-add.
+        Fma => "fma",
+        /// Enable ARMv8 FP.
+        FpArmv8 => "fp-armv8",
+        /// Enable half-precision floating-point data processing.
+        Fp16 => "fp16",
+        /// Support 64-bit FP registers.
+        Fp64 => "fp64",
+        /// Enable FP8 instructions.
+        Fp8 => "fp8",
+        /// Enable FP8 2-way dot instructions.
+        Fp8dot2 => "fp8dot2",
+        /// Enable FP8 4-way dot instructions.
+        Fp8dot4 => "fp8dot4",
+        /// Enable Armv9.5-A FP8 multiply-add instructions.
+        Fp8fma => "fp8fma",
+        /// Enable FP registers.
+        Fpregs => "fpregs",
+        /// Support frecipe.{s/d} and frsqrte.{s/d} instructions.
+        Frecipe => "frecipe",
+        /// Enable FRInt\[32|64\]\[Z|X\] instructions that round a floating-point number to an integer (in FP format) forcing it to fit into a 32- or 64-bit int.
+        Frintts => "frintts",
+        /// Support fxsave/fxrestore instructions.
+        Fxsr => "fxsr",
+        /// Enable wasm gc.
+        Gc => "gc",
+        /// Enable Galois Field Arithmetic Instructions.
+        Gfni => "gfni",
+        /// Assume that the guarded-storage facility is installed.
+        GuardedStorage => "guarded-storage",
+        /// Enable Armv8.8-A Hinted Conditional Branches Extension.
+        Hbc => "hbc",
+        /// Assume that the high-word facility is installed.
+        HighWord => "high-word",
+        /// Enable Matrix Multiply Int8 Extension.
+        I8mm => "i8mm",
+        /// Enable Armv8.3-A JavaScript FP conversion instructions.
+        Jsconv => "jsconv",
+        /// Support Key Locker kl Instructions.
+        Kl => "kl",
+        /// Support LAHF and SAHF instructions in 64-bit mode.
+        Lahfsahf => "lahfsahf",
+        /// Support amswap\[_db\].{b/h} and amadd\[_db\].{b/h} instructions.
+        LamBh => "lam-bh",
+        /// Support amcas\[_db\].{b/h/w/d}.
+        Lamcas => "lamcas",
+        /// 'LASX' (Loongson Advanced SIMD Extension).
+        Lasx => "lasx",
+        /// 'LBT' (Loongson Binary Translation Extension).
+        Lbt => "lbt",
+        /// Don't use a same-address load-load barrier (dbar 0x700).
+        LdSeqSa => "ld-seq-sa",
+        /// Enable Armv8.1-A Limited Ordering Regions extension.
+        Lor => "lor",
+        /// Enable Armv8.1-A Large System Extension (LSE) atomic instructions.
+        Lse => "lse",
+        /// Enable Armv9.4-A 128-bit Atomic instructions.
+        Lse128 => "lse128",
+        /// Enable Armv8.4-A Large System Extension 2 (LSE2) atomicity rules.
+        Lse2 => "lse2",
+        /// 'LSX' (Loongson SIMD Extension).
+        Lsx => "lsx",
+        /// Enable Lookup Table instructions.
+        Lut => "lut",
+        /// 'LVZ' (Loongson Virtualization Extension).
+        Lvz => "lvz",
+        /// Support LZCNT instruction.
+        Lzcnt => "lzcnt",
+        /// 'M' (Integer Multiplication and Division).
+        M => "m",
+        /// Is microcontroller profile ('M' series).
+        Mclass => "mclass",
+        /// Assume that the message-security-assist extension facility 12 is installed.
+        MessageSecurityAssistExtension12 => "message-security-assist-extension12",
+        /// Assume that the message-security-assist extension facility 3 is installed.
+        MessageSecurityAssistExtension3 => "message-security-assist-extension3",
+        /// Assume that the message-security-assist extension facility 4 is installed.
+        MessageSecurityAssistExtension4 => "message-security-assist-extension4",
+        /// Assume that the message-security-assist extension facility 5 is installed.
+        MessageSecurityAssistExtension5 => "message-security-assist-extension5",
+        /// Assume that the message-security-assist extension facility 8 is installed.
+        MessageSecurityAssistExtension8 => "message-security-assist-extension8",
+        /// Assume that the message-security-assist extension facility 9 is installed.
+        MessageSecurityAssistExtension9 => "message-security-assist-extension9",
+        /// Assume that the miscellaneous-extensions facility 2 is installed.
+        MiscellaneousExtensions2 => "miscellaneous-extensions-2",
+        /// Assume that the miscellaneous-extensions facility 3 is installed.
+        MiscellaneousExtensions3 => "miscellaneous-extensions-3",
+        /// Assume that the miscellaneous-extensions facility 4 is installed.
+        MiscellaneousExtensions4 => "miscellaneous-extensions-4",
+        /// Enable Armv8.8-A memcpy and memset acceleration instructions.
+        Mops => "mops",
+        /// Support MOVBE instruction.
+        Movbe => "movbe",
+        /// Enable MOVRS.
+        Movrs => "movrs",
+        /// Mips MSA ASE.
+        Msa => "msa",
+        /// Has only the msync instruction instead of sync.
+        Msync => "msync",
+        /// Enable Memory Tagging Extension.
+        Mte => "mte",
+        /// Enable multivalue blocks, instructions, and functions.
+        Multivalue => "multivalue",
+        /// Enable mutable globals.
+        MutableGlobals => "mutable-globals",
+        /// Enable Advanced SIMD instructions.
+        Neon => "neon",
+        /// Assume that the NNP-assist facility is installed.
+        NnpAssist => "nnp-assist",
+        /// Enable non-trapping float-to-int conversion operators.
+        NontrappingFptoint => "nontrapping-fptoint",
+        /// Enable out of line atomics to support LSE instructions.
+        OutlineAtomics => "outline-atomics",
+        /// Enable Armv8.3-A Pointer Authentication extension.
+        Paca => "paca",
+        /// Enable Armv8.3-A Pointer Authentication extension.
+        Pacg => "pacg",
+        /// Enable Armv8.1-A Privileged Access-Never extension.
+        Pan => "pan",
+        /// Enable l\[bh\]arx and st\[bh\]cx..
+        PartwordAtomics => "partword-atomics",
+        /// Enable Armv9.5-A PAC enhancements.
+        PauthLr => "pauth-lr",
+        /// Enable packed carry-less multiplication instructions.
+        Pclmulqdq => "pclmulqdq",
+        /// Enable Armv8.0-A PMUv3 Performance Monitors extension.
+        Pmuv3 => "pmuv3",
+        /// Support POPCNT instruction.
+        Popcnt => "popcnt",
+        /// Enable POWER10 vector instructions.
+        Power10Vector => "power10-vector",
+        /// Enable POWER8 Altivec instructions.
+        Power8Altivec => "power8-altivec",
+        /// Enable POWER8 Crypto instructions.
+        Power8Crypto => "power8-crypto",
+        /// Enable POWER8 vector instructions.
+        Power8Vector => "power8-vector",
+        /// Enable POWER9 Altivec instructions.
+        Power9Altivec => "power9-altivec",
+        /// Enable POWER9 vector instructions.
+        Power9Vector => "power9-vector",
+        /// Support PRFCHW instructions.
+        Prfchw => "prfchw",
+        /// Enable lqarx and stqcx..
+        QuadwordAtomics => "quadword-atomics",
+        /// Enable Random Number generation instructions.
+        Rand => "rand",
+        /// Enable Armv8.0-A Reliability, Availability and Serviceability Extensions.
+        Ras => "ras",
+        /// Is realtime profile ('R' series).
+        Rclass => "rclass",
+        /// Enable support for RCPC extension.
+        Rcpc => "rcpc",
+        /// Enable Armv8.4-A RCPC instructions with Immediate Offsets.
+        Rcpc2 => "rcpc2",
+        /// Enable Armv8.9-A RCPC instructions for A64 and Advanced SIMD and floating-point instruction set.
+        Rcpc3 => "rcpc3",
+        /// Enable Armv8.1-A Rounding Double Multiply Add/Subtract instructions.
+        Rdm => "rdm",
+        /// Support RDRAND instruction.
+        Rdrand => "rdrand",
+        /// Support RDSEED instruction.
+        Rdseed => "rdseed",
+        /// Enable reference types.
+        ReferenceTypes => "reference-types",
+        /// Enable Linker relaxation..
+        Relax => "relax",
+        /// Enable relaxed-simd instructions.
+        RelaxedSimd => "relaxed-simd",
+        /// Support RTM instructions.
+        Rtm => "rtm",
+        /// RISC-V rva23u64 profile.
+        Rva23u64 => "rva23u64",
+        /// Enable Armv8.5-A Speculation Barrier.
+        Sb => "sb",
+        /// Support sc.q instruction.
+        Scq => "scq",
+        /// Enable SHA instructions.
+        Sha => "sha",
+        /// Enable SHA1 and SHA256 support.
+        Sha2 => "sha2",
+        /// Enable SHA512 and SHA3 support.
+        Sha3 => "sha3",
+        /// Support SHA512 instructions.
+        Sha512 => "sha512",
+        /// Enable sign extension operators.
+        SignExt => "sign-ext",
+        /// Enable 128-bit SIMD.
+        Simd128 => "simd128",
+        /// Support SM3 instructions.
+        Sm3 => "sm3",
+        /// Support SM4 instructions.
+        Sm4 => "sm4",
+        /// Enable Scalable Matrix Extension (SME).
+        Sme => "sme",
+        /// Enable SME2.1 ZA-targeting non-widening BFloat16 instructions.
+        SmeB16b16 => "sme-b16b16",
+        /// Enable SME non-widening Float16 instructions.
+        SmeF16f16 => "sme-f16f16",
+        /// Enable Scalable Matrix Extension (SME) F64F64 instructions.
+        SmeF64f64 => "sme-f64f64",
+        /// Enable Scalable Matrix Extension (SME) F8F16 instructions.
+        SmeF8f16 => "sme-f8f16",
+        /// Enable Scalable Matrix Extension (SME) F8F32 instructions.
+        SmeF8f32 => "sme-f8f32",
+        /// Enable the full A64 instruction set in streaming SVE mode.
+        SmeFa64 => "sme-fa64",
+        /// Enable Scalable Matrix Extension (SME) I16I64 instructions.
+        SmeI16i64 => "sme-i16i64",
+        /// Enable Scalable Matrix Extension (SME) LUTv2 instructions.
+        SmeLutv2 => "sme-lutv2",
+        /// Enable Scalable Matrix Extension 2 (SME2) instructions.
+        Sme2 => "sme2",
+        /// Enable Scalable Matrix Extension 2.1 instructions.
+        Sme2p1 => "sme2p1",
+        /// Use software floating point features..
+        SoftFloat => "soft-float",
+        /// Enable Statistical Profiling extension.
+        Spe => "spe",
+        /// Enable Speculative Store Bypass Safe bit.
+        Ssbs => "ssbs",
+        /// Enable SSE instructions.
+        Sse => "sse",
+        /// Enable SSE2 instructions.
+        Sse2 => "sse2",
+        /// Enable SSE3 instructions.
+        Sse3 => "sse3",
+        /// Enable SSE 4.1 instructions.
+        Sse4_1 => "sse4.1",
+        /// Enable SSE 4.2 instructions.
+        Sse4_2 => "sse4.2",
+        /// Support SSE 4a instructions.
+        Sse4a => "sse4a",
+        /// Enable SSSE3 instructions.
+        Ssse3 => "ssse3",
+        /// Enable SVE2 FP8 2-way dot product instructions.
+        SsveFp8dot2 => "ssve-fp8dot2",
+        /// Enable SVE2 FP8 4-way dot product instructions.
+        SsveFp8dot4 => "ssve-fp8dot4",
+        /// Enable SVE2 FP8 multiply-add instructions.
+        SsveFp8fma => "ssve-fp8fma",
+        /// 'Supm' (Indicates User-mode Pointer Masking).
+        Supm => "supm",
+        /// Enable Scalable Vector Extension (SVE) instructions.
+        Sve => "sve",
+        /// Enable SVE2 non-widening and SME2 Z-targeting non-widening BFloat16 instructions.
+        SveB16b16 => "sve-b16b16",
+        /// Enable Scalable Vector Extension 2 (SVE2) instructions.
+        Sve2 => "sve2",
+        /// Shorthand for +sve2+sve-aes.
+        Sve2Aes => "sve2-aes",
+        /// Shorthand for +sve2+sve-bitperm.
+        Sve2Bitperm => "sve2-bitperm",
+        /// Shorthand for +sve2+sve-sha3.
+        Sve2Sha3 => "sve2-sha3",
+        /// Shorthand for +sve2+sve-sm4.
+        Sve2Sm4 => "sve2-sm4",
+        /// Enable Scalable Vector Extension 2.1 instructions.
+        Sve2p1 => "sve2p1",
+        /// Enable tail call instructions.
+        TailCall => "tail-call",
+        /// Enable TBM instructions.
+        Tbm => "tbm",
+        /// Thumb mode.
+        ThumbMode => "thumb-mode",
+        /// Enable Thumb2 instructions.
+        Thumb2 => "thumb2",
+        /// Assume that the transactional-execution facility is installed.
+        TransactionalExecution => "transactional-execution",
+        /// Enable support for TrustZone security extensions.
+        Trustzone => "trustzone",
+        /// Allow memory accesses to be unaligned.
+        Ual => "ual",
+        /// Has reasonably performant unaligned scalar loads and stores.
+        UnalignedScalarMem => "unaligned-scalar-mem",
+        /// Has reasonably performant unaligned vector loads and stores.
+        UnalignedVectorMem => "unaligned-vector-mem",
+        /// 'V' (Vector Extension for Application Processors).
+        V => "v",
+        /// Support ARM v5TE, v5TEj, and v5TExp instructions.
+        V5te => "v5te",
+        /// Support ARM v6 instructions.
+        V6 => "v6",
+        /// Support ARM v6k instructions.
+        V6k => "v6k",
+        /// Support ARM v6t2 instructions.
+        V6t2 => "v6t2",
+        /// Support ARM v7 instructions.
+        V7 => "v7",
+        /// Support ARM v8 instructions.
+        V8 => "v8",
+        /// Support ARM v8.1a architecture.
+        V8_1a => "v8.1a",
+        /// Support ARM v8.2a architecture.
+        V8_2a => "v8.2a",
+        /// Support ARM v8.3a architecture.
+        V8_3a => "v8.3a",
+        /// Support ARM v8.4a architecture.
+        V8_4a => "v8.4a",
+        /// Support ARM v8.5a architecture.
+        V8_5a => "v8.5a",
+        /// Support ARM v8.6a architecture.
+        V8_6a => "v8.6a",
+        /// Support ARM v8.7a architecture.
+        V8_7a => "v8.7a",
+        /// Support ARM v8.8a architecture.
+        V8_8a => "v8.8a",
+        /// Support ARM v8.9a architecture.
+        V8_9a => "v8.9a",
+        /// Support ARM v9.1a architecture.
+        V9_1a => "v9.1a",
+        /// Support ARM v9.2a architecture.
+        V9_2a => "v9.2a",
+        /// Support ARM v9.3a architecture.
+        V9_3a => "v9.3a",
+        /// Support ARM v9.4a architecture.
+        V9_4a => "v9.4a",
+        /// Support ARM v9.5a architecture.
+        V9_5a => "v9.5a",
+        /// Support ARM v9a architecture.
+        V9a => "v9a",
+        /// Promote selected AES instructions to AVX512/AVX registers.
+        Vaes => "vaes",
+        /// Assume that the vectory facility is installed.
+        Vector => "vector",
+        /// Assume that the vector enhancements facility 1 is installed.
+        VectorEnhancements1 => "vector-enhancements-1",
+        /// Assume that the vector enhancements facility 2 is installed.
+        VectorEnhancements2 => "vector-enhancements-2",
+        /// Assume that the vector enhancements facility 3 is installed.
+        VectorEnhancements3 => "vector-enhancements-3",
+        /// Assume that the vector packed decimal facility is installed.
+        VectorPackedDecimal => "vector-packed-decimal",
+        /// Assume that the vector packed decimal enhancement facility is installed.
+        VectorPackedDecimalEnhancement => "vector-packed-decimal-enhancement",
+        /// Assume that the vector packed decimal enhancement facility 2 is installed.
+        VectorPackedDecimalEnhancement2 => "vector-packed-decimal-enhancement-2",
+        /// Assume that the vector packed decimal enhancement facility 3 is installed.
+        VectorPackedDecimalEnhancement3 => "vector-packed-decimal-enhancement-3",
+        /// Enable VFP2 instructions.
+        Vfp2 => "vfp2",
+        /// Enable VFP3 instructions.
+        Vfp3 => "vfp3",
+        /// Enable VFP4 instructions.
+        Vfp4 => "vfp4",
+        /// Enable Armv8.1-A Virtual Host extension.
+        Vh => "vh",
+        /// Mips Virtualization ASE.
+        Virt => "virt",
+        /// Supports Virtualization extension.
+        Virtualization => "virtualization",
+        /// Enable vpclmulqdq instructions.
+        Vpclmulqdq => "vpclmulqdq",
+        /// Enable VSX instructions.
+        Vsx => "vsx",
+        /// Enable Armv8.7-A WFET and WFIT instruction.
+        Wfxt => "wfxt",
+        /// Enable wide-arithmetic instructions.
+        WideArithmetic => "wide-arithmetic",
+        /// Support Key Locker wide Instructions.
+        Widekl => "widekl",
+        /// Enable X87 float instructions.
+        X87 => "x87",
+        /// Enable XOP instructions.
+        Xop => "xop",
+        /// Support xsave instructions.
+        Xsave => "xsave",
+        /// Support xsavec instructions.
+        Xsavec => "xsavec",
+        /// Support xsaveopt instructions.
+        Xsaveopt => "xsaveopt",
+        /// Support xsaves instructions.
+        Xsaves => "xsaves",
+        /// 'Za128rs' (Reservation Set Size of at Most 128 Bytes).
+        Za128rs => "za128rs",
+        /// 'Za64rs' (Reservation Set Size of at Most 64 Bytes).
+        Za64rs => "za64rs",
+        /// 'Zaamo' (Atomic Memory Operations).
+        Zaamo => "zaamo",
+        /// 'Zabha' (Byte and Halfword Atomic Memory Operations).
+        Zabha => "zabha",
+        /// 'Zacas' (Atomic Compare-And-Swap Instructions).
+        Zacas => "zacas",
+        /// 'Zalrsc' (Load-Reserved/Store-Conditional).
+        Zalrsc => "zalrsc",
+        /// 'Zama16b' (Atomic 16-byte misaligned loads, stores and AMOs).
+        Zama16b => "zama16b",
+        /// 'Zawrs' (Wait on Reservation Set).
+        Zawrs => "zawrs",
+        /// 'Zba' (Address Generation Instructions).
+        Zba => "zba",
+        /// 'Zbb' (Basic Bit-Manipulation).
+        Zbb => "zbb",
+        /// 'Zbc' (Carry-Less Multiplication).
+        Zbc => "zbc",
+        /// 'Zbkb' (Bitmanip instructions for Cryptography).
+        Zbkb => "zbkb",
+        /// 'Zbkc' (Carry-less multiply instructions for Cryptography).
+        Zbkc => "zbkc",
+        /// 'Zbkx' (Crossbar permutation instructions).
+        Zbkx => "zbkx",
+        /// 'Zbs' (Single-Bit Instructions).
+        Zbs => "zbs",
+        /// 'Zca' (part of the C extension, excluding compressed floating point loads/stores).
+        Zca => "zca",
+        /// 'Zcb' (Compressed basic bit manipulation instructions).
+        Zcb => "zcb",
+        /// 'Zcmop' (Compressed May-Be-Operations).
+        Zcmop => "zcmop",
+        /// 'Zdinx' (Double in Integer).
+        Zdinx => "zdinx",
+        /// 'Zfa' (Additional Floating-Point).
+        Zfa => "zfa",
+        /// 'Zfbfmin' (Scalar BF16 Converts).
+        Zfbfmin => "zfbfmin",
+        /// 'Zfh' (Half-Precision Floating-Point).
+        Zfh => "zfh",
+        /// 'Zfhmin' (Half-Precision Floating-Point Minimal).
+        Zfhmin => "zfhmin",
+        /// 'Zfinx' (Float in Integer).
+        Zfinx => "zfinx",
+        /// 'Zhinx' (Half Float in Integer).
+        Zhinx => "zhinx",
+        /// 'Zhinxmin' (Half Float in Integer Minimal).
+        Zhinxmin => "zhinxmin",
+        /// 'Zic64b' (Cache Block Size Is 64 Bytes).
+        Zic64b => "zic64b",
+        /// 'Zicbom' (Cache-Block Management Instructions).
+        Zicbom => "zicbom",
+        /// 'Zicbop' (Cache-Block Prefetch Instructions).
+        Zicbop => "zicbop",
+        /// 'Zicboz' (Cache-Block Zero Instructions).
+        Zicboz => "zicboz",
+        /// 'Ziccamoa' (Main Memory Supports All Atomics in A).
+        Ziccamoa => "ziccamoa",
+        /// 'Ziccif' (Main Memory Supports Instruction Fetch with Atomicity Requirement).
+        Ziccif => "ziccif",
+        /// 'Zicclsm' (Main Memory Supports Misaligned Loads/Stores).
+        Zicclsm => "zicclsm",
+        /// 'Ziccrse' (Main Memory Supports Forward Progress on LR/SC Sequences).
+        Ziccrse => "ziccrse",
+        /// 'Zicntr' (Base Counters and Timers).
+        Zicntr => "zicntr",
+        /// 'Zicond' (Integer Conditional Operations).
+        Zicond => "zicond",
+        /// 'Zicsr' (CSRs).
+        Zicsr => "zicsr",
+        /// 'Zifencei' (fence.i).
+        Zifencei => "zifencei",
+        /// 'Zihintntl' (Non-Temporal Locality Hints).
+        Zihintntl => "zihintntl",
+        /// 'Zihintpause' (Pause Hint).
+        Zihintpause => "zihintpause",
+        /// 'Zihpm' (Hardware Performance Counters).
+        Zihpm => "zihpm",
+        /// 'Zimop' (May-Be-Operations).
+        Zimop => "zimop",
+        /// 'Zk' (Standard scalar cryptography extension).
+        Zk => "zk",
+        /// 'Zkn' (NIST Algorithm Suite).
+        Zkn => "zkn",
+        /// 'Zknd' (NIST Suite: AES Decryption).
+        Zknd => "zknd",
+        /// 'Zkne' (NIST Suite: AES Encryption).
+        Zkne => "zkne",
+        /// 'Zknh' (NIST Suite: Hash Function Instructions).
+        Zknh => "zknh",
+        /// 'Zkr' (Entropy Source Extension).
+        Zkr => "zkr",
+        /// 'Zks' (ShangMi Algorithm Suite).
+        Zks => "zks",
+        /// 'Zksed' (ShangMi Suite: SM4 Block Cipher Instructions).
+        Zksed => "zksed",
+        /// 'Zksh' (ShangMi Suite: SM3 Hash Function Instructions).
+        Zksh => "zksh",
+        /// 'Zkt' (Data Independent Execution Latency).
+        Zkt => "zkt",
+        /// 'Ztso' (Memory Model - Total Store Order).
+        Ztso => "ztso",
+        /// 'Zvbb' (Vector basic bit-manipulation instructions).
+        Zvbb => "zvbb",
+        /// 'Zvbc' (Vector Carryless Multiplication).
+        Zvbc => "zvbc",
+        /// 'Zve32f' (Vector Extensions for Embedded Processors with maximal 32 EEW and F extension).
+        Zve32f => "zve32f",
+        /// 'Zve32x' (Vector Extensions for Embedded Processors with maximal 32 EEW).
+        Zve32x => "zve32x",
+        /// 'Zve64d' (Vector Extensions for Embedded Processors with maximal 64 EEW, F and D extension).
+        Zve64d => "zve64d",
+        /// 'Zve64f' (Vector Extensions for Embedded Processors with maximal 64 EEW and F extension).
+        Zve64f => "zve64f",
+        /// 'Zve64x' (Vector Extensions for Embedded Processors with maximal 64 EEW).
+        Zve64x => "zve64x",
+        /// 'Zvfbfmin' (Vector BF16 Converts).
+        Zvfbfmin => "zvfbfmin",
+        /// 'Zvfbfwma' (Vector BF16 widening mul-add).
+        Zvfbfwma => "zvfbfwma",
+        /// 'Zvfh' (Vector Half-Precision Floating-Point).
+        Zvfh => "zvfh",
+        /// 'Zvfhmin' (Vector Half-Precision Floating-Point Minimal).
+        Zvfhmin => "zvfhmin",
+        /// 'Zvkb' (Vector Bit-manipulation used in Cryptography).
+        Zvkb => "zvkb",
+        /// 'Zvkg' (Vector GCM instructions for Cryptography).
+        Zvkg => "zvkg",
+        /// 'Zvkn' (shorthand for 'Zvkned', 'Zvknhb', 'Zvkb', and 'Zvkt').
+        Zvkn => "zvkn",
+        /// 'Zvknc' (shorthand for 'Zvknc' and 'Zvbc').
+        Zvknc => "zvknc",
+        /// 'Zvkned' (Vector AES Encryption & Decryption (Single Round)).
+        Zvkned => "zvkned",
+        /// 'Zvkng' (shorthand for 'Zvkn' and 'Zvkg').
+        Zvkng => "zvkng",
+        /// 'Zvknha' (Vector SHA-2 (SHA-256 only)).
+        Zvknha => "zvknha",
+        /// 'Zvknhb' (Vector SHA-2 (SHA-256 and SHA-512)).
+        Zvknhb => "zvknhb",
+        /// 'Zvks' (shorthand for 'Zvksed', 'Zvksh', 'Zvkb', and 'Zvkt').
+        Zvks => "zvks",
+        /// 'Zvksc' (shorthand for 'Zvks' and 'Zvbc').
+        Zvksc => "zvksc",
+        /// 'Zvksed' (SM4 Block Cipher Instructions).
+        Zvksed => "zvksed",
+        /// 'Zvksg' (shorthand for 'Zvks' and 'Zvkg').
+        Zvksg => "zvksg",
+        /// 'Zvksh' (SM3 Hash Function Instructions).
+        Zvksh => "zvksh",
+        /// 'Zvkt' (Vector Data-Independent Execution Latency).
+        Zvkt => "zvkt",
+        /// 'Zvl1024b' (Minimum Vector Length 1024).
+        Zvl1024b => "zvl1024b",
+        /// 'Zvl128b' (Minimum Vector Length 128).
+        Zvl128b => "zvl128b",
+        /// 'Zvl16384b' (Minimum Vector Length 16384).
+        Zvl16384b => "zvl16384b",
+        /// 'Zvl2048b' (Minimum Vector Length 2048).
+        Zvl2048b => "zvl2048b",
+        /// 'Zvl256b' (Minimum Vector Length 256).
+        Zvl256b => "zvl256b",
+        /// 'Zvl32768b' (Minimum Vector Length 32768).
+        Zvl32768b => "zvl32768b",
+        /// 'Zvl32b' (Minimum Vector Length 32).
+        Zvl32b => "zvl32b",
+        /// 'Zvl4096b' (Minimum Vector Length 4096).
+        Zvl4096b => "zvl4096b",
+        /// 'Zvl512b' (Minimum Vector Length 512).
+        Zvl512b => "zvl512b",
+        /// 'Zvl64b' (Minimum Vector Length 64).
+        Zvl64b => "zvl64b",
+        /// 'Zvl65536b' (Minimum Vector Length 65536).
+        Zvl65536b => "zvl65536b",
+        /// 'Zvl8192b' (Minimum Vector Length 8192).
+        Zvl8192b => "zvl8192b",
+    }
+
+    as_str_doc = "String representing this target feature which matches `#[cfg(target_feature)]`.",
+    from_str_doc = "Tries to parse the given string as a [`TargetFeature`] falling back to [`TargetFeature::Other`] for unknown values.",
+}
+
+impl TargetFeature {
+    /// Checks whether this feature can be enabled on the given [`Arch`].
+    ///
+    /// Returns `false` for [`TargetFeature::Other`] as well as for all features on architectures without known features.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn is_available_on(&self, arch: &Arch) -> bool {
+        if *self == Self::CrtStatic {
+            return true;
+        }
+
+        match arch {
+            Arch::X86 | Arch::X86_64 => matches!(
+                self,
+                Self::Adx
+                    | Self::Aes
+                    | Self::AmxAvx512
+                    | Self::AmxBf16
+                    | Self::AmxComplex
+                    | Self::AmxFp16
+                    | Self::AmxFp8
+                    | Self::AmxInt8
+                    | Self::AmxMovrs
+                    | Self::AmxTf32
+                    | Self::AmxTile
+                    | Self::Apxf
+                    | Self::Avx
+                    | Self::Avx10_1
+                    | Self::Avx10_2
+                    | Self::Avx2
+                    | Self::Avx512bf16
+                    | Self::Avx512bitalg
+                    | Self::Avx512bw
+                    | Self::Avx512cd
+                    | Self::Avx512dq
+                    | Self::Avx512f
+                    | Self::Avx512fp16
+                    | Self::Avx512ifma
+                    | Self::Avx512vbmi
+                    | Self::Avx512vbmi2
+                    | Self::Avx512vl
+                    | Self::Avx512vnni
+                    | Self::Avx512vp2intersect
+                    | Self::Avx512vpopcntdq
+                    | Self::Avxifma
+                    | Self::Avxneconvert
+                    | Self::Avxvnni
+                    | Self::Avxvnniint16
+                    | Self::Avxvnniint8
+                    | Self::Bmi1
+                    | Self::Bmi2
+                    | Self::Cmpxchg16b
+                    | Self::Ermsb
+                    | Self::F16c
+                    | Self::Fma
+                    | Self::Fxsr
+                    | Self::Gfni
+                    | Self::Kl
+                    | Self::Lahfsahf
+                    | Self::Lzcnt
+                    | Self::Movbe
+                    | Self::Movrs
+                    | Self::Pclmulqdq
+                    | Self::Popcnt
+                    | Self::Prfchw
+                    | Self::Rdrand
+                    | Self::Rdseed
+                    | Self::Rtm
+                    | Self::Sha
+                    | Self::Sha512
+                    | Self::Sm3
+                    | Self::Sm4
+                    | Self::Sse
+                    | Self::Sse2
+                    | Self::Sse3
+                    | Self::Sse4_1
+                    | Self::Sse4_2
+                    | Self::Sse4a
+                    | Self::Ssse3
+                    | Self::Tbm
+                    | Self::Vaes
+                    | Self::Vpclmulqdq
+                    | Self::Widekl
+                    | Self::X87
+                    | Self::Xop
+                    | Self::Xsave
+                    | Self::Xsavec
+                    | Self::Xsaveopt
+                    | Self::Xsaves
+            ),
+            Arch::AArch64 | Arch::Arm64ec => matches!(
+                self,
+                Self::Aes
+                    | Self::Bf16
+                    | Self::Bti
+                    | Self::Crc
+                    | Self::Cssc
+                    | Self::Dit
+                    | Self::Dotprod
+                    | Self::Dpb
+                    | Self::Dpb2
+                    | Self::Ecv
+                    | Self::F32mm
+                    | Self::F64mm
+                    | Self::Faminmax
+                    | Self::Fcma
+                    | Self::Fhm
+                    | Self::Flagm
+                    | Self::Flagm2
+                    | Self::Fp16
+                    | Self::Fp8
+                    | Self::Fp8dot2
+                    | Self::Fp8dot4
+                    | Self::Fp8fma
+                    | Self::Frintts
+                    | Self::Hbc
+                    | Self::I8mm
+                    | Self::Jsconv
+                    | Self::Lor
+                    | Self::Lse
+                    | Self::Lse128
+                    | Self::Lse2
+                    | Self::Lut
+                    | Self::Mops
+                    | Self::Mte
+                    | Self::Neon
+                    | Self::OutlineAtomics
+                    | Self::Paca
+                    | Self::Pacg
+                    | Self::Pan
+                    | Self::PauthLr
+                    | Self::Pmuv3
+                    | Self::Rand
+                    | Self::Ras
+                    | Self::Rcpc
+                    | Self::Rcpc2
+                    | Self::Rcpc3
+                    | Self::Rdm
+                    | Self::Sb
+                    | Self::Sha2
+                    | Self::Sha3
+                    | Self::Sm4
+                    | Self::Sme
+                    | Self::SmeB16b16
+                    | Self::SmeF16f16
+                    | Self::SmeF64f64
+                    | Self::SmeF8f16
+                    | Self::SmeF8f32
+                    | Self::SmeFa64
+                    | Self::SmeI16i64
+                    | Self::SmeLutv2
+                    | Self::Sme2
+                    | Self::Sme2p1
+                    | Self::Spe
+                    | Self::Ssbs
+                    | Self::SsveFp8dot2
+                    | Self::SsveFp8dot4
+                    | Self::SsveFp8fma
+                    | Self::Sve
+                    | Self::SveB16b16
+                    | Self::Sve2
+                    | Self::Sve2Aes
+                    | Self::Sve2Bitperm
+                    | Self::Sve2Sha3
+                    | Self::Sve2Sm4
+                    | Self::Sve2p1
+                    | Self::V8_1a
+                    | Self::V8_2a
+                    | Self::V8_3a
+                    | Self::V8_4a
+                    | Self::V8_5a
+                    | Self::V8_6a
+                    | Self::V8_7a
+                    | Self::V8_8a
+                    | Self::V8_9a
+                    | Self::V9_1a
+                    | Self::V9_2a
+                    | Self::V9_3a
+                    | Self::V9_4a
+                    | Self::V9_5a
+                    | Self::V9a
+                    | Self::Vh
+                    | Self::Wfxt
+            ),
+            Arch::Arm => matches!(
+                self,
+                Self::Aclass
+                    | Self::Aes
+                    | Self::Crc
+                    | Self::D32
+                    | Self::Dotprod
+                    | Self::Dsp
+                    | Self::FpArmv8
+                    | Self::Fp16
+                    | Self::Fpregs
+                    | Self::I8mm
+                    | Self::Mclass
+                    | Self::Neon
+                    | Self::Rclass
+                    | Self::Sha2
+                    | Self::SoftFloat
+                    | Self::ThumbMode
+                    | Self::Thumb2
+                    | Self::Trustzone
+                    | Self::V5te
+                    | Self::V6
+                    | Self::V6k
+                    | Self::V6t2
+                    | Self::V7
+                    | Self::V8
+                    | Self::Vfp2
+                    | Self::Vfp3
+                    | Self::Vfp4
+                    | Self::Virtualization
+            ),
+            Arch::Riscv32 | Arch::Riscv64 => matches!(
+                self,
+                Self::A
+                    | Self::B
+                    | Self::C
+                    | Self::D
+                    | Self::E
+                    | Self::F
+                    | Self::M
+                    | Self::Relax
+                    | Self::Rva23u64
+                    | Self::Supm
+                    | Self::UnalignedScalarMem
+                    | Self::UnalignedVectorMem
+                    | Self::V
+                    | Self::Za128rs
+                    | Self::Za64rs
+                    | Self::Zaamo
+                    | Self::Zabha
+                    | Self::Zacas
+                    | Self::Zalrsc
+                    | Self::Zama16b
+                    | Self::Zawrs
+                    | Self::Zba
+                    | Self::Zbb
+                    | Self::Zbc
+                    | Self::Zbkb
+                    | Self::Zbkc
+                    | Self::Zbkx
+                    | Self::Zbs
+                    | Self::Zca
+                    | Self::Zcb
+                    | Self::Zcmop
+                    | Self::Zdinx
+                    | Self::Zfa
+                    | Self::Zfbfmin
+                    | Self::Zfh
+                    | Self::Zfhmin
+                    | Self::Zfinx
+                    | Self::Zhinx
+                    | Self::Zhinxmin
+                    | Self::Zic64b
+                    | Self::Zicbom
+                    | Self::Zicbop
+                    | Self::Zicboz
+                    | Self::Ziccamoa
+                    | Self::Ziccif
+                    | Self::Zicclsm
+                    | Self::Ziccrse
+                    | Self::Zicntr
+                    | Self::Zicond
+                    | Self::Zicsr
+                    | Self::Zifencei
+                    | Self::Zihintntl
+                    | Self::Zihintpause
+                    | Self::Zihpm
+                    | Self::Zimop
+                    | Self::Zk
+                    | Self::Zkn
+                    | Self::Zknd
+                    | Self::Zkne
+                    | Self::Zknh
+                    | Self::Zkr
+                    | Self::Zks
+                    | Self::Zksed
+                    | Self::Zksh
+                    | Self::Zkt
+                    | Self::Ztso
+                    | Self::Zvbb
+                    | Self::Zvbc
+                    | Self::Zve32f
+                    | Self::Zve32x
+                    | Self::Zve64d
+                    | Self::Zve64f
+                    | Self::Zve64x
+                    | Self::Zvfbfmin
+                    | Self::Zvfbfwma
+                    | Self::Zvfh
+                    | Self::Zvfhmin
+                    | Self::Zvkb
+                    | Self::Zvkg
+                    | Self::Zvkn
+                    | Self::Zvknc
+                    | Self::Zvkned
+                    | Self::Zvkng
+                    | Self::Zvknha
+                    | Self::Zvknhb
+                    | Self::Zvks
+                    | Self::Zvksc
+                    | Self::Zvksed
+                    | Self::Zvksg
+                    | Self::Zvksh
+                    | Self::Zvkt
+                    | Self::Zvl1024b
+                    | Self::Zvl128b
+                    | Self::Zvl16384b
+                    | Self::Zvl2048b
+                    | Self::Zvl256b
+                    | Self::Zvl32768b
+                    | Self::Zvl32b
+                    | Self::Zvl4096b
+                    | Self::Zvl512b
+                    | Self::Zvl64b
+                    | Self::Zvl65536b
+                    | Self::Zvl8192b
+            ),
+            Arch::Wasm32 | Arch::Wasm64 => matches!(
+                self,
+                Self::Atomics
+                    | Self::BulkMemory
+                    | Self::ExceptionHandling
+                    | Self::ExtendedConst
+                    | Self::Gc
+                    | Self::Multivalue
+                    | Self::MutableGlobals
+                    | Self::NontrappingFptoint
+                    | Self::ReferenceTypes
+                    | Self::RelaxedSimd
+                    | Self::SignExt
+                    | Self::Simd128
+                    | Self::TailCall
+                    | Self::WideArithmetic
+            ),
+            Arch::Loongarch32 | Arch::Loongarch64 => matches!(
+                self,
+                Self::La32s
+                    | Self::D
+                    | Self::Div32
+                    | Self::F
+                    | Self::Frecipe
+                    | Self::LamBh
+                    | Self::Lamcas
+                    | Self::Lasx
+                    | Self::Lbt
+                    | Self::LdSeqSa
+                    | Self::Lsx
+                    | Self::Lvz
+                    | Self::Relax
+                    | Self::Scq
+                    | Self::Ual
+            ),
+            Arch::PowerPc | Arch::PowerPc64 => matches!(
+                self,
+                Self::Altivec
+                    | Self::Msync
+                    | Self::PartwordAtomics
+                    | Self::Power10Vector
+                    | Self::Power8Altivec
+                    | Self::Power8Crypto
+                    | Self::Power8Vector
+                    | Self::Power9Altivec
+                    | Self::Power9Vector
+                    | Self::QuadwordAtomics
+                    | Self::Vsx
+            ),
+            Arch::S390X => matches!(
+                self,
+                Self::Backchain
+                    | Self::ConcurrentFunctions
+                    | Self::DeflateConversion
+                    | Self::EnhancedSort
+                    | Self::GuardedStorage
+                    | Self::HighWord
+                    | Self::MessageSecurityAssistExtension12
+                    | Self::MessageSecurityAssistExtension3
+                    | Self::MessageSecurityAssistExtension4
+                    | Self::MessageSecurityAssistExtension5
+                    | Self::MessageSecurityAssistExtension8
+                    | Self::MessageSecurityAssistExtension9
+                    | Self::MiscellaneousExtensions2
+                    | Self::MiscellaneousExtensions3
+                    | Self::MiscellaneousExtensions4
+                    | Self::NnpAssist
+                    | Self::TransactionalExecution
+                    | Self::Vector
+                    | Self::VectorEnhancements1
+                    | Self::VectorEnhancements2
+                    | Self::VectorEnhancements3
+                    | Self::VectorPackedDecimal
+                    | Self::VectorPackedDecimalEnhancement
+                    | Self::VectorPackedDecimalEnhancement2
+                    | Self::VectorPackedDecimalEnhancement3
+            ),
+            Arch::Mips | Arch::Mips32r6 | Arch::Mips64 | Arch::Mips64r6 => {
+                matches!(self, Self::Fp64 | Self::Msa | Self::Virt)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for TargetFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Set of [`TargetFeature`]s enabled for a target.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct TargetFeatures(BTreeSet<TargetFeature>);
+
+impl TargetFeatures {
+    /// Creates an empty set of features.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the [`TargetFeatures`] enabled for the current target.
+    #[must_use]
+    pub fn target() -> Self {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the [`TargetFeatures`] enabled for the current target.
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`TargetFeatures`] enabled for the target from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        Ok(Self::from_list(
+            &build_env_opt(env, "CARGO_CFG_TARGET_FEATURE")?.unwrap_or_default(),
+        ))
+    }
+
+    /// Parses a comma separated list of features such as `fxsr,sse,sse2,crt-static`.
+    pub(crate) fn from_list(list: &str) -> Self {
+        list.split(',')
+            .filter(|s| !s.is_empty())
+            .map(TargetFeature::from_str)
+            .collect()
+    }
+
+    /// Checks whether the given feature is enabled.
+    #[must_use]
+    pub fn contains(&self, feature: &TargetFeature) -> bool {
+        self.0.contains(feature)
+    }
+
+    /// Adds the given feature to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, feature: TargetFeature) -> bool {
+        self.0.insert(feature)
+    }
+
+    /// Removes the given feature from the set, returning whether it was present.
+    pub fn remove(&mut self, feature: &TargetFeature) -> bool {
+        self.0.remove(feature)
+    }
+
+    /// Gets the number of enabled features.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether no features are enabled.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the enabled features in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = &TargetFeature> {
+        self.0.iter()
+    }
+
+    /// Iterates over the enabled features which belong to the given [`Arch`] (see [`TargetFeature::is_available_on()`]).
+    pub fn for_arch<'a>(&'a self, arch: &'a Arch) -> impl Iterator<Item = &'a TargetFeature> {
+        self.iter()
+            .filter(move |feature| feature.is_available_on(arch))
+    }
+}
+
+impl FromIterator<TargetFeature> for TargetFeatures {
+    fn from_iter<T: IntoIterator<Item = TargetFeature>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<TargetFeature> for TargetFeatures {
+    fn extend<T: IntoIterator<Item = TargetFeature>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for TargetFeatures {
+    type Item = TargetFeature;
+    type IntoIter = std::collections::btree_set::IntoIter<TargetFeature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TargetFeatures {
+    type Item = &'a TargetFeature;
+    type IntoIter = std::collections::btree_set::Iter<'a, TargetFeature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for TargetFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, feature) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(feature.as_str())?;
+        }
+        Ok(())
+    }
+}
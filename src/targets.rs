@@ -0,0 +1,486 @@
+//! Database of the built-in rustc targets.
+//!
+//! The table below was generated from the output of `rustc --print cfg --target <triple>` for every target
+//! listed by `rustc --print target-list` (rustc 1.95.0), so it contains the same values Cargo exposes
+//! through the `CARGO_CFG_*` environment variables when building for the respective target.
+
+use std::sync::OnceLock;
+
+use crate::{
+    Abi, Arch, AtomicSupport, Endian, Env, Family, Os, PointerWidth, Target, TargetFeatures, Vendor,
+};
+
+/// Gets all built-in rustc targets (tier 1, 2 and 3) sorted by their triple.
+///
+/// # Example
+/// ```rust
+/// let linux_targets = build_target::targets::all()
+///     .iter()
+///     .filter(|target| target.os == build_target::Os::Linux)
+///     .count();
+/// assert!(linux_targets > 0);
+/// ```
+#[must_use]
+pub fn all() -> &'static [Target] {
+    static TARGETS: OnceLock<Vec<Target>> = OnceLock::new();
+    TARGETS.get_or_init(|| TARGETS_RAW.iter().map(RawTarget::to_target).collect())
+}
+
+/// Looks up the built-in target with the given triple. This function is equivalent to [`Target::lookup()`].
+#[must_use]
+pub fn lookup(triple: &str) -> Option<&'static Target> {
+    let targets = all();
+    targets
+        .binary_search_by(|target| target.triple.as_str().cmp(triple))
+        .ok()
+        .map(|index| &targets[index])
+}
+
+struct RawTarget {
+    triple: &'static str,
+    arch: &'static str,
+    endian: &'static str,
+    env: &'static str,
+    abi: &'static str,
+    os: &'static str,
+    pointer_width: &'static str,
+    family: &'static str,
+    vendor: &'static str,
+    features: &'static str,
+    has_atomic: &'static str,
+}
+
+impl RawTarget {
+    fn to_target(&self) -> Target {
+        Target {
+            arch: Arch::from_str(self.arch),
+            endian: Endian::from_str(self.endian),
+            env: Some(self.env)
+                .filter(|env| !env.is_empty())
+                .map(Env::from_str),
+            abi: Some(self.abi)
+                .filter(|abi| !abi.is_empty())
+                .map(Abi::from_str),
+            os: Os::from_str(self.os),
+            pointer_width: PointerWidth::from_str(self.pointer_width),
+            family: self
+                .family
+                .split(',')
+                .filter(|family| !family.is_empty())
+                .map(Family::from_str)
+                .collect(),
+            vendor: Vendor::from_str(self.vendor),
+            features: TargetFeatures::from_list(self.features),
+            atomics: AtomicSupport {
+                has_atomic: AtomicSupport::widths_from_list(self.has_atomic),
+                ..AtomicSupport::default()
+            },
+            triple: self.triple.to_string(),
+        }
+    }
+}
+
+macro_rules! target {
+    ($triple:literal, $arch:literal, $endian:literal, $env:literal, $abi:literal, $os:literal, $pointer_width:literal, $family:literal, $vendor:literal, $features:literal, $has_atomic:literal $(,)?) => {
+        RawTarget {
+            triple: $triple,
+            arch: $arch,
+            endian: $endian,
+            env: $env,
+            abi: $abi,
+            os: $os,
+            pointer_width: $pointer_width,
+            family: $family,
+            vendor: $vendor,
+            features: $features,
+            has_atomic: $has_atomic,
+        }
+    };
+}
+
+#[rustfmt::skip]
+static TARGETS_RAW: &[RawTarget] = &[
+    //      triple, arch, endian, env, abi, os, pointer_width, family, vendor, features, has_atomic
+    target!("aarch64-apple-darwin", "aarch64", "little", "", "", "macos", "64", "unix", "apple", "aes,crc,dit,dotprod,dpb,dpb2,fcma,fhm,flagm,fp16,frintts,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rcpc2,rdm,sb,sha2,sha3,ssbs,vh", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-ios", "aarch64", "little", "", "", "ios", "64", "unix", "apple", "aes,neon,pmuv3,sha2", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-ios-macabi", "aarch64", "little", "macabi", "macabi", "ios", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-ios-sim", "aarch64", "little", "sim", "sim", "ios", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-tvos", "aarch64", "little", "", "", "tvos", "64", "unix", "apple", "aes,neon,pmuv3,sha2", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-tvos-sim", "aarch64", "little", "sim", "sim", "tvos", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-visionos", "aarch64", "little", "", "", "visionos", "64", "unix", "apple", "aes,neon,pmuv3,sha2", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-visionos-sim", "aarch64", "little", "sim", "sim", "visionos", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-watchos", "aarch64", "little", "", "", "watchos", "64", "unix", "apple", "aes,neon,pmuv3,sha2", "8,16,32,64,128,ptr"),
+    target!("aarch64-apple-watchos-sim", "aarch64", "little", "sim", "sim", "watchos", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("aarch64-kmc-solid_asp3", "aarch64", "little", "", "", "solid_asp3", "64", "", "kmc", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-linux-android", "aarch64", "little", "", "", "android", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-nintendo-switch-freestanding", "aarch64", "little", "", "", "horizon", "64", "", "nintendo", "aes,crc,neon,sha2", "8,16,32,64,128,ptr"),
+    target!("aarch64-pc-windows-gnullvm", "aarch64", "little", "gnu", "llvm", "windows", "64", "windows", "pc", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-pc-windows-msvc", "aarch64", "little", "msvc", "", "windows", "64", "windows", "pc", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-freebsd", "aarch64", "little", "", "", "freebsd", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-fuchsia", "aarch64", "little", "", "", "fuchsia", "64", "unix", "unknown", "aes,crc,neon,sha2", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-helenos", "aarch64", "little", "", "", "helenos", "64", "", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-hermit", "aarch64", "little", "", "", "hermit", "64", "", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-illumos", "aarch64", "little", "", "", "illumos", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-linux-gnu", "aarch64", "little", "gnu", "", "linux", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-linux-gnu_ilp32", "aarch64", "little", "gnu", "ilp32", "linux", "32", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-linux-musl", "aarch64", "little", "musl", "", "linux", "64", "unix", "unknown", "crt-static,neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-linux-ohos", "aarch64", "little", "ohos", "", "linux", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-managarm-mlibc", "aarch64", "little", "mlibc", "", "managarm", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-netbsd", "aarch64", "little", "", "", "netbsd", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-none", "aarch64", "little", "", "", "none", "64", "", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-none-softfloat", "aarch64", "little", "", "softfloat", "none", "64", "", "unknown", "", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-nto-qnx700", "aarch64", "little", "nto70", "", "nto", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-nto-qnx710", "aarch64", "little", "nto71", "", "nto", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-nto-qnx710_iosock", "aarch64", "little", "nto71_iosock", "", "nto", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-nto-qnx800", "aarch64", "little", "nto80", "", "nto", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-nuttx", "aarch64", "little", "", "", "nuttx", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-openbsd", "aarch64", "little", "", "", "openbsd", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-redox", "aarch64", "little", "relibc", "", "redox", "64", "unix", "unknown", "crt-static,neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-teeos", "aarch64", "little", "", "", "teeos", "64", "", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-trusty", "aarch64", "little", "", "", "trusty", "64", "", "unknown", "crt-static,neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-unknown-uefi", "aarch64", "little", "", "", "uefi", "64", "", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-uwp-windows-msvc", "aarch64", "little", "msvc", "uwp", "windows", "64", "windows", "uwp", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64-wrs-vxworks", "aarch64", "little", "gnu", "", "vxworks", "64", "unix", "wrs", "crt-static,neon", "8,16,32,64,128,ptr"),
+    target!("aarch64_be-unknown-hermit", "aarch64", "big", "", "", "hermit", "64", "", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64_be-unknown-linux-gnu", "aarch64", "big", "gnu", "", "linux", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64_be-unknown-linux-gnu_ilp32", "aarch64", "big", "gnu", "ilp32", "linux", "32", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64_be-unknown-linux-musl", "aarch64", "big", "musl", "", "linux", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64_be-unknown-netbsd", "aarch64", "big", "", "", "netbsd", "64", "unix", "unknown", "neon", "8,16,32,64,128,ptr"),
+    target!("aarch64_be-unknown-none-softfloat", "aarch64", "big", "", "softfloat", "none", "64", "", "unknown", "", "8,16,32,64,128,ptr"),
+    target!("aarch64v8r-unknown-none", "aarch64", "little", "", "", "none", "64", "", "unknown", "crc,dit,dpb,flagm,lse,neon,paca,pacg,pan,ras,rcpc,rcpc2", "8,16,32,64,128,ptr"),
+    target!("aarch64v8r-unknown-none-softfloat", "aarch64", "little", "", "softfloat", "none", "64", "", "unknown", "crc,dit,dpb,flagm,lse,paca,pacg,pan,ras,rcpc,rcpc2", "8,16,32,64,128,ptr"),
+    target!("amdgcn-amd-amdhsa", "amdgpu", "little", "", "", "amdhsa", "64", "", "amd", "", "8,16,32,64,ptr"),
+    target!("arm-linux-androideabi", "arm", "little", "", "eabi", "android", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("arm-unknown-linux-gnueabi", "arm", "little", "gnu", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("arm-unknown-linux-gnueabihf", "arm", "little", "gnu", "eabihf", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("arm-unknown-linux-musleabi", "arm", "little", "musl", "eabi", "linux", "32", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("arm-unknown-linux-musleabihf", "arm", "little", "musl", "eabihf", "linux", "32", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("arm64_32-apple-watchos", "aarch64", "little", "", "", "watchos", "32", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("arm64e-apple-darwin", "aarch64", "little", "", "", "macos", "64", "unix", "apple", "aes,crc,dit,dotprod,dpb,dpb2,fcma,fhm,flagm,fp16,frintts,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rcpc2,rdm,sb,sha2,sha3,ssbs,vh", "8,16,32,64,128,ptr"),
+    target!("arm64e-apple-ios", "aarch64", "little", "", "", "ios", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("arm64e-apple-tvos", "aarch64", "little", "", "", "tvos", "64", "unix", "apple", "aes,crc,dpb,fcma,fp16,jsconv,lor,lse,neon,paca,pacg,pan,pmuv3,ras,rcpc,rdm,sha2,vh", "8,16,32,64,128,ptr"),
+    target!("arm64ec-pc-windows-msvc", "arm64ec", "little", "msvc", "", "windows", "64", "windows", "pc", "neon", "8,16,32,64,128,ptr"),
+    target!("armeb-unknown-linux-gnueabi", "arm", "big", "gnu", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armebv7r-none-eabi", "arm", "big", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armebv7r-none-eabihf", "arm", "big", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv4t-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", ""),
+    target!("armv4t-unknown-linux-gnueabi", "arm", "little", "gnu", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("armv5te-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", ""),
+    target!("armv5te-unknown-linux-gnueabi", "arm", "little", "gnu", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("armv5te-unknown-linux-musleabi", "arm", "little", "musl", "eabi", "linux", "32", "unix", "unknown", "crt-static", "8,16,32,ptr"),
+    target!("armv5te-unknown-linux-uclibceabi", "arm", "little", "uclibc", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("armv6-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv6-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv6-unknown-freebsd", "arm", "little", "", "eabihf", "freebsd", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv6-unknown-netbsd-eabihf", "arm", "little", "", "eabihf", "netbsd", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv6k-nintendo-3ds", "arm", "little", "newlib", "eabihf", "horizon", "32", "unix", "nintendo", "", "8,16,32,ptr"),
+    target!("armv7-linux-androideabi", "arm", "little", "", "eabi", "android", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-rtems-eabihf", "arm", "little", "newlib", "eabihf", "rtems", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-sony-vita-newlibeabihf", "arm", "little", "newlib", "eabihf", "vita", "32", "unix", "sony", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-freebsd", "arm", "little", "", "eabihf", "freebsd", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-gnueabi", "arm", "little", "gnu", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-gnueabihf", "arm", "little", "gnu", "eabihf", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-musleabi", "arm", "little", "musl", "eabi", "linux", "32", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-musleabihf", "arm", "little", "musl", "eabihf", "linux", "32", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-ohos", "arm", "little", "ohos", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-uclibceabi", "arm", "little", "uclibc", "eabi", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-linux-uclibceabihf", "arm", "little", "uclibc", "eabihf", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-netbsd-eabihf", "arm", "little", "", "eabihf", "netbsd", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7-unknown-trusty", "arm", "little", "", "eabi", "trusty", "32", "", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("armv7-wrs-vxworks-eabihf", "arm", "little", "gnu", "eabihf", "vxworks", "32", "unix", "wrs", "crt-static", "8,16,32,64,ptr"),
+    target!("armv7a-kmc-solid_asp3-eabi", "arm", "little", "", "eabi", "solid_asp3", "32", "", "kmc", "", "8,16,32,64,ptr"),
+    target!("armv7a-kmc-solid_asp3-eabihf", "arm", "little", "", "eabihf", "solid_asp3", "32", "", "kmc", "", "8,16,32,64,ptr"),
+    target!("armv7a-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7a-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7a-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7a-nuttx-eabihf", "arm", "little", "", "eabihf", "nuttx", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7a-vex-v5", "arm", "little", "v5", "eabihf", "vexos", "32", "", "vex", "", "8,16,32,64,ptr"),
+    target!("armv7k-apple-watchos", "arm", "little", "", "", "watchos", "32", "unix", "apple", "", "8,16,32,64,ptr"),
+    target!("armv7r-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7r-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("armv7s-apple-ios", "arm", "little", "", "", "ios", "32", "unix", "apple", "", "8,16,32,64,ptr"),
+    target!("armv8r-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("avr-none", "avr", "little", "", "", "none", "16", "", "unknown", "", ""),
+    target!("bpfeb-unknown-none", "bpf", "big", "", "", "none", "64", "", "unknown", "", ""),
+    target!("bpfel-unknown-none", "bpf", "little", "", "", "none", "64", "", "unknown", "", ""),
+    target!("csky-unknown-linux-gnuabiv2", "csky", "little", "gnu", "abiv2", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("csky-unknown-linux-gnuabiv2hf", "csky", "little", "gnu", "abiv2hf", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("hexagon-unknown-linux-musl", "hexagon", "little", "musl", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("hexagon-unknown-none-elf", "hexagon", "little", "", "", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("hexagon-unknown-qurt", "hexagon", "little", "", "", "qurt", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("i386-apple-ios", "x86", "little", "sim", "sim", "ios", "32", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,ptr"),
+    target!("i586-unknown-linux-gnu", "x86", "little", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("i586-unknown-linux-musl", "x86", "little", "musl", "", "linux", "32", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("i586-unknown-netbsd", "x86", "little", "", "", "netbsd", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("i586-unknown-redox", "x86", "little", "relibc", "", "redox", "32", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("i686-apple-darwin", "x86", "little", "", "", "macos", "32", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,ptr"),
+    target!("i686-linux-android", "x86", "little", "", "", "android", "32", "unix", "unknown", "fxsr,sse,sse2,sse3,ssse3", "8,16,32,64,ptr"),
+    target!("i686-pc-nto-qnx700", "x86", "little", "nto70", "", "nto", "32", "unix", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-pc-windows-gnu", "x86", "little", "gnu", "", "windows", "32", "windows", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-pc-windows-gnullvm", "x86", "little", "gnu", "llvm", "windows", "32", "windows", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-pc-windows-msvc", "x86", "little", "msvc", "", "windows", "32", "windows", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-freebsd", "x86", "little", "", "", "freebsd", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-haiku", "x86", "little", "", "", "haiku", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-helenos", "x86", "little", "", "", "helenos", "32", "", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-hurd-gnu", "x86", "little", "gnu", "", "hurd", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-linux-gnu", "x86", "little", "gnu", "", "linux", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-linux-musl", "x86", "little", "musl", "", "linux", "32", "unix", "unknown", "crt-static,fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-netbsd", "x86", "little", "", "", "netbsd", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-openbsd", "x86", "little", "", "", "openbsd", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-unknown-uefi", "x86", "little", "", "", "uefi", "32", "", "unknown", "fxsr", "8,16,32,64,ptr"),
+    target!("i686-uwp-windows-gnu", "x86", "little", "gnu", "uwp", "windows", "32", "windows", "uwp", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-uwp-windows-msvc", "x86", "little", "msvc", "uwp", "windows", "32", "windows", "uwp", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-win7-windows-gnu", "x86", "little", "gnu", "", "windows", "32", "windows", "win7", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-win7-windows-msvc", "x86", "little", "msvc", "", "windows", "32", "windows", "win7", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("i686-wrs-vxworks", "x86", "little", "gnu", "", "vxworks", "32", "unix", "wrs", "crt-static,fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("loongarch32-unknown-none", "loongarch32", "little", "", "", "none", "32", "", "unknown", "d,f", "8,16,32,ptr"),
+    target!("loongarch32-unknown-none-softfloat", "loongarch32", "little", "", "softfloat", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("loongarch64-unknown-linux-gnu", "loongarch64", "little", "gnu", "", "linux", "64", "unix", "unknown", "d,f,lsx", "8,16,32,64,ptr"),
+    target!("loongarch64-unknown-linux-musl", "loongarch64", "little", "musl", "", "linux", "64", "unix", "unknown", "d,f,lsx", "8,16,32,64,ptr"),
+    target!("loongarch64-unknown-linux-ohos", "loongarch64", "little", "ohos", "", "linux", "64", "unix", "unknown", "d,f,lsx", "8,16,32,64,ptr"),
+    target!("loongarch64-unknown-none", "loongarch64", "little", "", "", "none", "64", "", "unknown", "d,f", "8,16,32,64,ptr"),
+    target!("loongarch64-unknown-none-softfloat", "loongarch64", "little", "", "softfloat", "none", "64", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("m68k-unknown-linux-gnu", "m68k", "big", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("m68k-unknown-none-elf", "m68k", "big", "", "", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("mips-mti-none-elf", "mips", "big", "", "", "none", "32", "", "mti", "", "8,16,32,ptr"),
+    target!("mips-unknown-linux-gnu", "mips", "big", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mips-unknown-linux-musl", "mips", "big", "musl", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mips-unknown-linux-uclibc", "mips", "big", "uclibc", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mips64-openwrt-linux-musl", "mips64", "big", "musl", "abi64", "linux", "64", "unix", "openwrt", "", "8,16,32,64,ptr"),
+    target!("mips64-unknown-linux-gnuabi64", "mips64", "big", "gnu", "abi64", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("mips64-unknown-linux-muslabi64", "mips64", "big", "musl", "abi64", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("mips64el-unknown-linux-gnuabi64", "mips64", "little", "gnu", "abi64", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("mips64el-unknown-linux-muslabi64", "mips64", "little", "musl", "abi64", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("mipsel-mti-none-elf", "mips", "little", "", "", "none", "32", "", "mti", "", "8,16,32,ptr"),
+    target!("mipsel-sony-psp", "mips", "little", "", "", "psp", "32", "", "sony", "", "8,16,32,ptr"),
+    target!("mipsel-sony-psx", "mips", "little", "", "", "psx", "32", "", "sony", "", ""),
+    target!("mipsel-unknown-linux-gnu", "mips", "little", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mipsel-unknown-linux-musl", "mips", "little", "musl", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mipsel-unknown-linux-uclibc", "mips", "little", "uclibc", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mipsel-unknown-netbsd", "mips", "little", "", "", "netbsd", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mipsel-unknown-none", "mips", "little", "", "", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("mipsisa32r6-unknown-linux-gnu", "mips32r6", "big", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mipsisa32r6el-unknown-linux-gnu", "mips32r6", "little", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("mipsisa64r6-unknown-linux-gnuabi64", "mips64r6", "big", "gnu", "abi64", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("mipsisa64r6el-unknown-linux-gnuabi64", "mips64r6", "little", "gnu", "abi64", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("msp430-none-elf", "msp430", "little", "", "", "none", "16", "", "unknown", "", ""),
+    target!("nvptx64-nvidia-cuda", "nvptx64", "little", "", "", "cuda", "64", "", "nvidia", "", "8,16,32,64,ptr"),
+    target!("powerpc-unknown-freebsd", "powerpc", "big", "", "", "freebsd", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-helenos", "powerpc", "big", "", "", "helenos", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-linux-gnu", "powerpc", "big", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-linux-gnuspe", "powerpc", "big", "gnu", "spe", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-linux-musl", "powerpc", "big", "musl", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-linux-muslspe", "powerpc", "big", "musl", "spe", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-netbsd", "powerpc", "big", "", "", "netbsd", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-unknown-openbsd", "powerpc", "big", "", "", "openbsd", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("powerpc-wrs-vxworks", "powerpc", "big", "gnu", "", "vxworks", "32", "unix", "wrs", "crt-static", "8,16,32,ptr"),
+    target!("powerpc-wrs-vxworks-spe", "powerpc", "big", "gnu", "spe", "vxworks", "32", "unix", "wrs", "crt-static", "8,16,32,ptr"),
+    target!("powerpc64-ibm-aix", "powerpc64", "big", "", "vec-extabi", "aix", "64", "unix", "ibm", "", "8,16,32,64,ptr"),
+    target!("powerpc64-unknown-freebsd", "powerpc64", "big", "", "elfv2", "freebsd", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("powerpc64-unknown-linux-gnu", "powerpc64", "big", "gnu", "elfv1", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("powerpc64-unknown-linux-musl", "powerpc64", "big", "musl", "elfv2", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("powerpc64-unknown-openbsd", "powerpc64", "big", "", "elfv2", "openbsd", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("powerpc64-wrs-vxworks", "powerpc64", "big", "gnu", "elfv1", "vxworks", "64", "unix", "wrs", "crt-static", "8,16,32,64,ptr"),
+    target!("powerpc64le-unknown-freebsd", "powerpc64", "little", "", "elfv2", "freebsd", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("powerpc64le-unknown-linux-gnu", "powerpc64", "little", "gnu", "elfv2", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("powerpc64le-unknown-linux-musl", "powerpc64", "little", "musl", "elfv2", "linux", "64", "unix", "unknown", "crt-static", "8,16,32,64,ptr"),
+    target!("riscv32-wrs-vxworks", "riscv32", "little", "gnu", "", "vxworks", "32", "unix", "wrs", "a,c,crt-static,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,ptr"),
+    target!("riscv32e-unknown-none-elf", "riscv32", "little", "", "ilp32e", "none", "32", "", "unknown", "", ""),
+    target!("riscv32em-unknown-none-elf", "riscv32", "little", "", "ilp32e", "none", "32", "", "unknown", "m", ""),
+    target!("riscv32emc-unknown-none-elf", "riscv32", "little", "", "ilp32e", "none", "32", "", "unknown", "c,m,zca", ""),
+    target!("riscv32gc-unknown-linux-gnu", "riscv32", "little", "gnu", "", "linux", "32", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,ptr"),
+    target!("riscv32gc-unknown-linux-musl", "riscv32", "little", "musl", "", "linux", "32", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,ptr"),
+    target!("riscv32i-unknown-none-elf", "riscv32", "little", "", "", "none", "32", "", "unknown", "", ""),
+    target!("riscv32im-risc0-zkvm-elf", "riscv32", "little", "", "", "zkvm", "32", "", "risc0", "m", "8,16,32,64,ptr"),
+    target!("riscv32im-unknown-none-elf", "riscv32", "little", "", "", "none", "32", "", "unknown", "m", ""),
+    target!("riscv32ima-unknown-none-elf", "riscv32", "little", "", "", "none", "32", "", "unknown", "a,m,zaamo,zalrsc", "8,16,32,ptr"),
+    target!("riscv32imac-esp-espidf", "riscv32", "little", "newlib", "", "espidf", "32", "unix", "espressif", "a,c,m,zaamo,zalrsc,zca", "8,16,32,ptr"),
+    target!("riscv32imac-unknown-none-elf", "riscv32", "little", "", "", "none", "32", "", "unknown", "a,c,m,zaamo,zalrsc,zca", "8,16,32,ptr"),
+    target!("riscv32imac-unknown-nuttx-elf", "riscv32", "little", "", "", "nuttx", "32", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca", "8,16,32,ptr"),
+    target!("riscv32imac-unknown-xous-elf", "riscv32", "little", "", "", "xous", "32", "", "unknown", "a,c,m,zaamo,zalrsc,zca", "8,16,32,ptr"),
+    target!("riscv32imafc-esp-espidf", "riscv32", "little", "newlib", "", "espidf", "32", "unix", "espressif", "a,c,m,zaamo,zalrsc,zca,zicsr", "8,16,32,ptr"),
+    target!("riscv32imafc-unknown-none-elf", "riscv32", "little", "", "", "none", "32", "", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr", "8,16,32,ptr"),
+    target!("riscv32imafc-unknown-nuttx-elf", "riscv32", "little", "", "", "nuttx", "32", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr", "8,16,32,ptr"),
+    target!("riscv32imc-esp-espidf", "riscv32", "little", "newlib", "", "espidf", "32", "unix", "espressif", "c,m,zca", "8,16,32,ptr"),
+    target!("riscv32imc-unknown-none-elf", "riscv32", "little", "", "", "none", "32", "", "unknown", "c,m,zca", ""),
+    target!("riscv32imc-unknown-nuttx-elf", "riscv32", "little", "", "", "nuttx", "32", "unix", "unknown", "c,m,zca", "8,16,32,ptr"),
+    target!("riscv64-linux-android", "riscv64", "little", "", "", "android", "64", "unix", "unknown", "a,b,c,m,zaamo,zalrsc,zba,zbb,zbs,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64-wrs-vxworks", "riscv64", "little", "gnu", "", "vxworks", "64", "unix", "wrs", "a,c,crt-static,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64a23-unknown-linux-gnu", "riscv64", "little", "gnu", "", "linux", "64", "unix", "unknown", "a,b,c,m,za128rs,za64rs,zaamo,zalrsc,zawrs,zba,zbb,zbs,zca,zcb,zcmop,zic64b,zicbom,zicbop,zicboz,ziccamoa,ziccif,zicclsm,ziccrse,zicntr,zicond,zicsr,zihintntl,zihintpause,zihpm,zimop,zkt", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-freebsd", "riscv64", "little", "", "", "freebsd", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-fuchsia", "riscv64", "little", "", "", "fuchsia", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-hermit", "riscv64", "little", "", "", "hermit", "64", "", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-linux-gnu", "riscv64", "little", "gnu", "", "linux", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-linux-musl", "riscv64", "little", "musl", "", "linux", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-managarm-mlibc", "riscv64", "little", "mlibc", "", "managarm", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-netbsd", "riscv64", "little", "", "", "netbsd", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-none-elf", "riscv64", "little", "", "", "none", "64", "", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-nuttx-elf", "riscv64", "little", "", "", "nuttx", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-openbsd", "riscv64", "little", "", "", "openbsd", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca,zicsr,zifencei", "8,16,32,64,ptr"),
+    target!("riscv64gc-unknown-redox", "riscv64", "little", "relibc", "", "redox", "64", "unix", "unknown", "a,c,crt-static,m,zaamo,zalrsc,zca,zicsr", "8,16,32,64,ptr"),
+    target!("riscv64im-unknown-none-elf", "riscv64", "little", "", "", "none", "64", "", "unknown", "m", ""),
+    target!("riscv64imac-unknown-none-elf", "riscv64", "little", "", "", "none", "64", "", "unknown", "a,c,m,zaamo,zalrsc,zca", "8,16,32,64,ptr"),
+    target!("riscv64imac-unknown-nuttx-elf", "riscv64", "little", "", "", "nuttx", "64", "unix", "unknown", "a,c,m,zaamo,zalrsc,zca", "8,16,32,64,ptr"),
+    target!("s390x-unknown-linux-gnu", "s390x", "big", "gnu", "", "linux", "64", "unix", "unknown", "", "8,16,32,64,128,ptr"),
+    target!("s390x-unknown-linux-musl", "s390x", "big", "musl", "", "linux", "64", "unix", "unknown", "", "8,16,32,64,128,ptr"),
+    target!("s390x-unknown-none-softfloat", "s390x", "big", "", "softfloat", "none", "64", "", "unknown", "", "8,16,32,64,128,ptr"),
+    target!("sparc-unknown-linux-gnu", "sparc", "big", "gnu", "", "linux", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("sparc-unknown-none-elf", "sparc", "big", "", "", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("sparc64-unknown-helenos", "sparc64", "big", "", "", "helenos", "64", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("sparc64-unknown-linux-gnu", "sparc64", "big", "gnu", "", "linux", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("sparc64-unknown-netbsd", "sparc64", "big", "", "", "netbsd", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("sparc64-unknown-openbsd", "sparc64", "big", "", "", "openbsd", "64", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("sparcv9-sun-solaris", "sparc64", "big", "", "", "solaris", "64", "unix", "sun", "", "8,16,32,64,ptr"),
+    target!("thumbv4t-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", ""),
+    target!("thumbv5te-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", ""),
+    target!("thumbv6-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv6m-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", ""),
+    target!("thumbv6m-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7a-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7a-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7a-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7a-nuttx-eabihf", "arm", "little", "", "eabihf", "nuttx", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7a-pc-windows-msvc", "arm", "little", "msvc", "", "windows", "32", "windows", "pc", "", "8,16,32,64,ptr"),
+    target!("thumbv7a-uwp-windows-msvc", "arm", "little", "msvc", "uwp", "windows", "32", "windows", "uwp", "", "8,16,32,64,ptr"),
+    target!("thumbv7em-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7em-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7em-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7em-nuttx-eabihf", "arm", "little", "", "eabihf", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7m-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7m-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv7neon-linux-androideabi", "arm", "little", "", "eabi", "android", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7neon-unknown-linux-gnueabihf", "arm", "little", "gnu", "eabihf", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7neon-unknown-linux-musleabihf", "arm", "little", "musl", "eabihf", "linux", "32", "unix", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7r-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv7r-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("thumbv8m.base-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv8m.base-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv8m.main-none-eabi", "arm", "little", "", "eabi", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv8m.main-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv8m.main-nuttx-eabi", "arm", "little", "", "eabi", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv8m.main-nuttx-eabihf", "arm", "little", "", "eabihf", "nuttx", "32", "unix", "unknown", "", "8,16,32,ptr"),
+    target!("thumbv8r-none-eabihf", "arm", "little", "", "eabihf", "none", "32", "", "unknown", "", "8,16,32,64,ptr"),
+    target!("wasm32-unknown-emscripten", "wasm32", "little", "", "", "emscripten", "32", "unix,wasm", "unknown", "bulk-memory,crt-static,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32-unknown-unknown", "wasm32", "little", "", "", "unknown", "32", "wasm", "unknown", "bulk-memory,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32-wali-linux-musl", "wasm32", "little", "musl", "", "linux", "32", "unix,wasm", "unknown", "bulk-memory,crt-static,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32-wasip1", "wasm32", "little", "p1", "", "wasi", "32", "wasm", "unknown", "bulk-memory,crt-static,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32-wasip1-threads", "wasm32", "little", "p1", "", "wasi", "32", "wasm", "unknown", "bulk-memory,crt-static,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32-wasip2", "wasm32", "little", "p2", "", "wasi", "32", "wasm", "unknown", "bulk-memory,crt-static,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32-wasip3", "wasm32", "little", "p3", "", "wasi", "32", "wasm", "unknown", "bulk-memory,crt-static,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("wasm32v1-none", "wasm32", "little", "", "", "none", "32", "wasm", "unknown", "mutable-globals", "8,16,32,64,ptr"),
+    target!("wasm64-unknown-unknown", "wasm64", "little", "", "", "unknown", "64", "wasm", "unknown", "bulk-memory,multivalue,mutable-globals,nontrapping-fptoint,reference-types,sign-ext", "8,16,32,64,ptr"),
+    target!("x86_64-apple-darwin", "x86_64", "little", "", "", "macos", "64", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-apple-ios", "x86_64", "little", "sim", "sim", "ios", "64", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-apple-ios-macabi", "x86_64", "little", "macabi", "macabi", "ios", "64", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-apple-tvos", "x86_64", "little", "sim", "sim", "tvos", "64", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-apple-watchos-sim", "x86_64", "little", "sim", "sim", "watchos", "64", "unix", "apple", "cmpxchg16b,fxsr,sse,sse2,sse3,sse4.1,ssse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-fortanix-unknown-sgx", "x86_64", "little", "sgx", "fortanix", "unknown", "64", "", "fortanix", "fxsr,rdrand,rdseed,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-linux-android", "x86_64", "little", "", "", "android", "64", "unix", "unknown", "fxsr,popcnt,sse,sse2,sse3,sse4.1,sse4.2,ssse3", "8,16,32,64,ptr"),
+    target!("x86_64-lynx-lynxos178", "x86_64", "little", "", "", "lynxos178", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-pc-cygwin", "x86_64", "little", "", "", "cygwin", "64", "unix", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-pc-nto-qnx710", "x86_64", "little", "nto71", "", "nto", "64", "unix", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-pc-nto-qnx710_iosock", "x86_64", "little", "nto71_iosock", "", "nto", "64", "unix", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-pc-nto-qnx800", "x86_64", "little", "nto80", "", "nto", "64", "unix", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-pc-solaris", "x86_64", "little", "", "", "solaris", "64", "unix", "pc", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-pc-windows-gnu", "x86_64", "little", "gnu", "", "windows", "64", "windows", "pc", "cmpxchg16b,fxsr,sse,sse2,sse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-pc-windows-gnullvm", "x86_64", "little", "gnu", "llvm", "windows", "64", "windows", "pc", "cmpxchg16b,fxsr,sse,sse2,sse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-pc-windows-msvc", "x86_64", "little", "msvc", "", "windows", "64", "windows", "pc", "cmpxchg16b,fxsr,sse,sse2,sse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-unikraft-linux-musl", "x86_64", "little", "musl", "", "linux", "64", "unix", "unikraft", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-dragonfly", "x86_64", "little", "", "", "dragonfly", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-freebsd", "x86_64", "little", "", "", "freebsd", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-fuchsia", "x86_64", "little", "", "", "fuchsia", "64", "unix", "unknown", "cmpxchg16b,fxsr,popcnt,sse,sse2,sse3,sse4.1,sse4.2,ssse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-unknown-haiku", "x86_64", "little", "", "", "haiku", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-helenos", "x86_64", "little", "", "", "helenos", "64", "", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-hermit", "x86_64", "little", "", "", "hermit", "64", "", "unknown", "fxsr,rdrand,rdseed,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-hurd-gnu", "x86_64", "little", "gnu", "", "hurd", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-illumos", "x86_64", "little", "", "", "illumos", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-l4re-uclibc", "x86_64", "little", "uclibc", "", "l4re", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-linux-gnu", "x86_64", "little", "gnu", "", "linux", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-linux-gnuasan", "x86_64", "little", "gnu", "", "linux", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-linux-gnux32", "x86_64", "little", "gnu", "x32", "linux", "32", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-linux-musl", "x86_64", "little", "musl", "", "linux", "64", "unix", "unknown", "crt-static,fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-linux-none", "x86_64", "little", "", "", "linux", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-linux-ohos", "x86_64", "little", "ohos", "", "linux", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-managarm-mlibc", "x86_64", "little", "mlibc", "", "managarm", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-motor", "x86_64", "little", "", "", "motor", "64", "", "unknown", "crt-static,fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-netbsd", "x86_64", "little", "", "", "netbsd", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-none", "x86_64", "little", "", "", "none", "64", "", "unknown", "fxsr", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-openbsd", "x86_64", "little", "", "", "openbsd", "64", "unix", "unknown", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-redox", "x86_64", "little", "relibc", "", "redox", "64", "unix", "unknown", "crt-static,fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-trusty", "x86_64", "little", "", "", "trusty", "64", "", "unknown", "crt-static,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-unknown-uefi", "x86_64", "little", "", "", "uefi", "64", "", "unknown", "fxsr", "8,16,32,64,ptr"),
+    target!("x86_64-uwp-windows-gnu", "x86_64", "little", "gnu", "uwp", "windows", "64", "windows", "uwp", "cmpxchg16b,fxsr,sse,sse2,sse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-uwp-windows-msvc", "x86_64", "little", "msvc", "uwp", "windows", "64", "windows", "uwp", "cmpxchg16b,fxsr,sse,sse2,sse3", "8,16,32,64,128,ptr"),
+    target!("x86_64-win7-windows-gnu", "x86_64", "little", "gnu", "", "windows", "64", "windows", "win7", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-win7-windows-msvc", "x86_64", "little", "msvc", "", "windows", "64", "windows", "win7", "fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64-wrs-vxworks", "x86_64", "little", "gnu", "", "vxworks", "64", "unix", "wrs", "crt-static,fxsr,sse,sse2", "8,16,32,64,ptr"),
+    target!("x86_64h-apple-darwin", "x86_64", "little", "", "", "macos", "64", "unix", "apple", "avx,avx2,bmi1,bmi2,cmpxchg16b,f16c,fma,fxsr,lzcnt,movbe,popcnt,sse,sse2,sse3,sse4.1,sse4.2,ssse3,xsave,xsaveopt", "8,16,32,64,128,ptr"),
+    target!("xtensa-esp32-espidf", "xtensa", "little", "newlib", "", "espidf", "32", "unix", "espressif", "", "8,16,32,ptr"),
+    target!("xtensa-esp32-none-elf", "xtensa", "little", "", "", "none", "32", "", "espressif", "", "8,16,32,ptr"),
+    target!("xtensa-esp32s2-espidf", "xtensa", "little", "newlib", "", "espidf", "32", "unix", "espressif", "", "8,16,32,ptr"),
+    target!("xtensa-esp32s2-none-elf", "xtensa", "little", "", "", "none", "32", "", "espressif", "", ""),
+    target!("xtensa-esp32s3-espidf", "xtensa", "little", "newlib", "", "espidf", "32", "unix", "espressif", "", "8,16,32,ptr"),
+    target!("xtensa-esp32s3-none-elf", "xtensa", "little", "", "", "none", "32", "", "espressif", "", "8,16,32,ptr"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        assert!(TARGETS_RAW.is_sorted_by_key(|target| target.triple));
+    }
+
+    #[test]
+    fn known_values() {
+        for target in all() {
+            assert!(!matches!(target.arch, Arch::Other(_)), "{target}");
+            assert!(!matches!(target.endian, Endian::Other(_)), "{target}");
+            assert!(!matches!(target.env, Some(Env::Other(_))), "{target}");
+            assert!(!matches!(target.abi, Some(Abi::Other(_))), "{target}");
+            assert!(!matches!(target.os, Os::Other(_)), "{target}");
+            assert!(
+                !matches!(target.pointer_width, PointerWidth::Other(_)),
+                "{target}"
+            );
+            assert!(
+                !target
+                    .family
+                    .iter()
+                    .any(|family| matches!(family, Family::Other(_))),
+                "{target}"
+            );
+            assert!(!matches!(target.vendor, Vendor::Other(_)), "{target}");
+            assert!(
+                target
+                    .features
+                    .iter()
+                    .all(|feature| feature.is_available_on(&target.arch)),
+                "{target}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_parsed_triple() {
+        for target in all() {
+            let expected = Target {
+                features: TargetFeatures::new(),
+                atomics: AtomicSupport::default(),
+                ..target.clone()
+            };
+            assert_eq!(Target::from_triple(&target.triple), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let target = Target::lookup("x86_64-unknown-linux-musl").unwrap();
+        assert_eq!(target.arch, Arch::X86_64);
+        assert_eq!(target.env, Some(Env::Musl));
+        assert_eq!(target.os, Os::Linux);
+
+        assert_eq!(Target::lookup("x86_64-unknown-linux-foo"), None);
+    }
+}
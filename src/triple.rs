@@ -0,0 +1,709 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    Abi, Arch, AtomicSupport, CustomTargetSpec, Endian, Env, EnvSource, Error, Family, Os,
+    ParseTargetError, PointerWidth, ProcessEnv, Target, TargetFeatures, Vendor,
+    utils::{build_env, define_target_enum, expect_env},
+};
+
+define_target_enum! {
+    /// Sub-architecture of a target, i.e. the part of the architecture component of a triple that
+    /// [`Arch`] does not distinguish, such as the ARM version and profile in `thumbv7em`.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum SubArch {
+        /// ARMv4T
+        ArmV4t => "v4t",
+        /// ARMv5TE
+        ArmV5te => "v5te",
+        /// ARMv6
+        ArmV6 => "v6",
+        /// ARMv6K
+        ArmV6k => "v6k",
+        /// ARMv6-M (Cortex-M0, M0+ and M1)
+        ArmV6m => "v6m",
+        /// ARMv7
+        ArmV7 => "v7",
+        /// ARMv7-A
+        ArmV7a => "v7a",
+        /// ARMv7E-M (Cortex-M4 and M7)
+        ArmV7em => "v7em",
+        /// ARMv7-A as used by the Apple Watch
+        ArmV7k => "v7k",
+        /// ARMv7-M (Cortex-M3)
+        ArmV7m => "v7m",
+        /// ARMv7-A with NEON
+        ArmV7neon => "v7neon",
+        /// ARMv7-R
+        ArmV7r => "v7r",
+        /// ARMv7-A as used by Apple's Swift cores
+        ArmV7s => "v7s",
+        /// ARMv8-A in AArch32 state
+        ArmV8 => "v8",
+        /// ARMv8-M Baseline (Cortex-M23)
+        ArmV8mBase => "v8m.base",
+        /// ARMv8-M Mainline (Cortex-M33 and M35P)
+        ArmV8mMain => "v8m.main",
+        /// ARMv8-R
+        ArmV8r => "v8r",
+        /// ARMv8.3-A with pointer authentication as used by Apple (`arm64e`)
+        Arm64e => "arm64e",
+        /// Intel 80386
+        I386 => "i386",
+        /// Intel 80486
+        I486 => "i486",
+        /// Intel Pentium
+        I586 => "i586",
+        /// Intel Pentium Pro and later
+        I686 => "i686",
+        /// x86-64 Haswell and later as used by Apple (`x86_64h`)
+        X86_64h => "x86_64h",
+        /// RISC-V 32-bit base integer ISA (RV32I)
+        Rv32i => "rv32i",
+        /// RISC-V 32-bit reduced base integer ISA for embedded systems (RV32E)
+        Rv32e => "rv32e",
+        /// RISC-V 64-bit base integer ISA (RV64I)
+        Rv64i => "rv64i",
+        /// MIPS32 Release 6 (`mipsisa32r6`)
+        Mips32r6 => "mips32r6",
+        /// MIPS64 Release 6 (`mipsisa64r6`)
+        Mips64r6 => "mips64r6",
+    }
+
+    as_str_doc = "String representing this sub-architecture.",
+    from_str_doc = "Tries to parse the given string as a [`SubArch`] falling back to [`SubArch::Other`] for unknown values.",
+}
+
+impl SubArch {
+    /// Derives the [`SubArch`] from the architecture component of a triple, such as `thumbv7em` or `i586`.
+    ///
+    /// Returns [`None`] if the component does not specify a sub-architecture, e.g. for `x86_64` or `arm`.
+    #[must_use]
+    pub fn from_arch_component(arch: &str) -> Option<Self> {
+        match arch {
+            "arm64e" => return Some(Self::Arm64e),
+            "x86_64h" => return Some(Self::X86_64h),
+            "i386" | "i486" | "i586" | "i686" => return Some(Self::from_str(arch)),
+            _ => {}
+        }
+
+        if let Some(isa) = arch.strip_prefix("riscv32") {
+            return Some(if isa.starts_with('e') {
+                Self::Rv32e
+            } else {
+                Self::Rv32i
+            });
+        }
+        if arch.starts_with("riscv64") {
+            return Some(Self::Rv64i);
+        }
+        if let Some(isa) = arch.strip_prefix("mipsisa") {
+            // e.g. `mipsisa32r6el` for little endian
+            return match isa.trim_end_matches("el") {
+                "32r6" => Some(Self::Mips32r6),
+                "64r6" => Some(Self::Mips64r6),
+                _ => None,
+            };
+        }
+
+        let version = ["aarch64_be", "aarch64", "armeb", "arm", "thumbeb", "thumb"]
+            .into_iter()
+            .find_map(|prefix| arch.strip_prefix(prefix))?;
+        // `arm64_32`, `arm64ec` and the like are architectures of their own
+        version.starts_with('v').then(|| Self::from_str(version))
+    }
+}
+
+impl fmt::Display for SubArch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A target triple split into its raw components, e.g. `thumbv7em`, `none` and `eabihf` for
+/// `thumbv7em-none-eabihf`.
+///
+/// In contrast to [`Target`], the components are kept as written, so no information is lost by normalizing
+/// them, e.g. `armv7` and `thumbv7em` are both [`Arch::Arm`] but have different [`SubArch`]s.
+///
+/// # Example
+/// ```rust
+/// use build_target::{Arch, SubArch, Triple};
+///
+/// let triple: Triple = "thumbv7em-none-eabihf".parse().unwrap();
+/// assert_eq!(triple.arch, "thumbv7em");
+/// assert_eq!(triple.vendor, None);
+/// assert_eq!(triple.os, "none");
+/// assert_eq!(triple.env.as_deref(), Some("eabihf"));
+/// assert_eq!(triple.sub_arch(), Some(SubArch::ArmV7em));
+/// assert_eq!(triple.to_target().arch, Arch::Arm);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Triple {
+    /// The raw architecture component, such as `x86_64`, `armv7` or `riscv64gc`.
+    pub arch: String,
+    /// The raw vendor component, such as `unknown` or `apple`, if the triple has one.
+    pub vendor: Option<String>,
+    /// The raw operating system component, such as `linux`, `darwin` or `none`.
+    pub os: String,
+    /// The raw environment and ABI component, such as `gnu`, `gnueabihf` or `eabi`, if the triple has one.
+    pub env: Option<String>,
+}
+
+impl Triple {
+    /// Gets the triple of the current build target. This is based on [`target_triple()`](crate::target_triple).
+    ///
+    /// If the target is a custom target, the `llvm-target` of its specification is used. See
+    /// [`CustomTargetSpec::current()`] for how custom targets are detected.
+    #[must_use]
+    pub fn current() -> Self {
+        expect_env(Self::try_current())
+    }
+
+    /// Tries to get the triple of the current build target.
+    pub fn try_current() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the triple of the build target described by the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        let Some(spec) = CustomTargetSpec::from_env(env)? else {
+            return Ok(Self::parse(&build_env(env, "TARGET")?)?);
+        };
+        let triple = spec.llvm_target.ok_or_else(|| Error::InvalidTargetSpec {
+            name: spec.target.triple,
+            reason: "missing field `llvm-target`".to_string(),
+        })?;
+        Ok(Self::parse(&triple)?)
+    }
+
+    /// Splits the given triple into its components.
+    pub fn parse(triple: &str) -> Result<Self, ParseTargetError> {
+        let components = Components::split(triple).ok_or_else(|| ParseTargetError {
+            triple: triple.to_string(),
+        })?;
+        Ok(Self {
+            arch: components.arch.to_string(),
+            vendor: components.vendor.map(str::to_string),
+            os: components.os.to_string(),
+            env: components.env.map(str::to_string),
+        })
+    }
+
+    /// Gets the [`SubArch`] of the architecture component. See [`SubArch::from_arch_component()`].
+    #[must_use]
+    pub fn sub_arch(&self) -> Option<SubArch> {
+        SubArch::from_arch_component(&self.arch)
+    }
+
+    /// Derives a [`Target`] from this triple. This is equivalent to [`Target::from_triple()`].
+    #[must_use]
+    pub fn to_target(&self) -> Target {
+        self.components().to_target(&self.to_string())
+    }
+
+    pub(crate) fn components(&self) -> Components<'_> {
+        Components {
+            arch: &self.arch,
+            vendor: self.vendor.as_deref(),
+            os: &self.os,
+            env: self.env.as_deref(),
+        }
+    }
+}
+
+impl FromStr for Triple {
+    type Err = ParseTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Triple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.arch)?;
+        if let Some(vendor) = &self.vendor {
+            write!(f, "-{vendor}")?;
+        }
+        write!(f, "-{}", self.os)?;
+        if let Some(env) = &self.env {
+            write!(f, "-{env}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Triple> for Target {
+    fn from(triple: &Triple) -> Self {
+        triple.to_target()
+    }
+}
+
+impl From<Triple> for Target {
+    fn from(triple: Triple) -> Self {
+        triple.to_target()
+    }
+}
+
+/// The raw `-` separated components of a target triple.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Components<'a> {
+    pub(crate) arch: &'a str,
+    pub(crate) vendor: Option<&'a str>,
+    pub(crate) os: &'a str,
+    pub(crate) env: Option<&'a str>,
+}
+
+impl<'a> Components<'a> {
+    /// Splits the given triple into its components.
+    ///
+    /// Target triples come in a couple of shapes:
+    /// - `arch-os` (e.g. `wasm32-wasip1`, `avr-none`)
+    /// - `arch-vendor-os` (e.g. `x86_64-apple-darwin`)
+    /// - `arch-os-env` (e.g. `aarch64-linux-android`, `thumbv7m-none-eabi`)
+    /// - `arch-vendor-os-env` (e.g. `x86_64-unknown-linux-gnu`)
+    pub(crate) fn split(triple: &'a str) -> Option<Self> {
+        let parts = triple.split('-').collect::<Vec<_>>();
+        if parts.iter().any(|part| part.is_empty()) {
+            return None;
+        }
+
+        match parts[..] {
+            [arch, os] => Some(Self {
+                arch,
+                vendor: None,
+                os,
+                env: None,
+            }),
+            [arch, second, third] if is_os_component(second) => Some(Self {
+                arch,
+                vendor: None,
+                os: second,
+                env: Some(third),
+            }),
+            [arch, vendor, os] => Some(Self {
+                arch,
+                vendor: Some(vendor),
+                os,
+                env: None,
+            }),
+            [arch, vendor, os, env] => Some(Self {
+                arch,
+                vendor: Some(vendor),
+                os,
+                env: Some(env),
+            }),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn arch(&self) -> Arch {
+        let arch = self.arch;
+        match arch {
+            "x86_64" | "x86_64h" => Arch::X86_64,
+            "i386" | "i486" | "i586" | "i686" => Arch::X86,
+            "arm64ec" => Arch::Arm64ec,
+            "amdgcn" => Arch::Amdgpu,
+            "bpfeb" | "bpfel" => Arch::Bpf,
+            "sparcv9" => Arch::Sparc64,
+            _ if arch.starts_with("aarch64") || arch.starts_with("arm64") => Arch::AArch64,
+            _ if arch.starts_with("arm") || arch.starts_with("thumb") => Arch::Arm,
+            _ if arch.starts_with("mipsisa32r6") => Arch::Mips32r6,
+            _ if arch.starts_with("mipsisa64r6") => Arch::Mips64r6,
+            _ if arch.starts_with("mips64") => Arch::Mips64,
+            _ if arch.starts_with("mips") => Arch::Mips,
+            _ if arch.starts_with("powerpc64") => Arch::PowerPc64,
+            _ if arch.starts_with("riscv32") => Arch::Riscv32,
+            _ if arch.starts_with("riscv64") => Arch::Riscv64,
+            _ if arch.starts_with("wasm32") => Arch::Wasm32,
+            _ => Arch::from_str(arch),
+        }
+    }
+
+    pub(crate) fn endian(&self) -> Endian {
+        let arch = self.arch;
+        let big = if arch.starts_with("mips") {
+            !arch.ends_with("el")
+        } else if arch.starts_with("powerpc") {
+            !arch.ends_with("le")
+        } else {
+            arch.starts_with("aarch64_be")
+                || arch.starts_with("armeb")
+                || arch.starts_with("thumbeb")
+                || arch.starts_with("sparc")
+                || matches!(arch, "bpfeb" | "m68k" | "s390x")
+        };
+
+        if big { Endian::Big } else { Endian::Little }
+    }
+
+    pub(crate) fn pointer_width(&self) -> PointerWidth {
+        let env = self.env.unwrap_or_default();
+        if self.arch == "arm64_32"
+            || env.ends_with("x32")
+            || env.ends_with("ilp32")
+            || env.ends_with("abin32")
+        {
+            return PointerWidth::U32;
+        }
+
+        match self.arch() {
+            Arch::Avr | Arch::Msp430 => PointerWidth::U16,
+            Arch::AArch64
+            | Arch::Amdgpu
+            | Arch::Arm64ec
+            | Arch::Bpf
+            | Arch::Loongarch64
+            | Arch::Mips64
+            | Arch::Mips64r6
+            | Arch::Nvptx64
+            | Arch::PowerPc64
+            | Arch::Riscv64
+            | Arch::S390X
+            | Arch::Sparc64
+            | Arch::Wasm64
+            | Arch::X86_64 => PointerWidth::U64,
+            _ => PointerWidth::U32,
+        }
+    }
+
+    pub(crate) fn vendor(&self) -> Vendor {
+        match self.vendor {
+            None | Some("lynx" | "wali") => Vendor::Unknown,
+            Some(vendor) if vendor.starts_with("esp") => Vendor::Espressif,
+            Some(vendor) => Vendor::from_str(vendor),
+        }
+    }
+
+    pub(crate) fn os(&self) -> Os {
+        if self.env.is_some_and(|env| env.starts_with("android")) {
+            return Os::Android;
+        }
+
+        match self.os {
+            "darwin" => Os::MacOS,
+            "switch" | "3ds" => Os::Horizon,
+            "v5" => Os::VexOS,
+            os if os.starts_with("wasi") => Os::Wasi,
+            os => Os::from_str(os),
+        }
+    }
+
+    pub(crate) fn env(&self) -> Option<Env> {
+        let env = match (self.os, self.env) {
+            (_, Some(env)) if env.starts_with("android") => return None,
+            (os, _) if os.starts_with("wasip") => os.strip_prefix("wasi"),
+            ("espidf" | "3ds" | "rtems", _) => Some("newlib"),
+            ("vxworks", _) => Some("gnu"),
+            ("redox", _) => Some("relibc"),
+            ("v5", _) => Some("v5"),
+            ("ios" | "tvos" | "watchos" | "visionos", None)
+                if matches!(self.arch(), Arch::X86 | Arch::X86_64) =>
+            {
+                Some("sim")
+            }
+            (_, Some("qnx700")) => Some("nto70"),
+            (_, Some("qnx710")) => Some("nto71"),
+            (_, Some("qnx710_iosock")) => Some("nto71_iosock"),
+            (_, Some("qnx800")) => Some("nto80"),
+            (_, Some(env)) => ["gnu", "musl", "uclibc", "newlib"]
+                .into_iter()
+                .find(|libc| env.starts_with(libc))
+                .or(match env {
+                    "eabi" | "eabihf" | "elf" | "softfloat" | "freestanding" | "none" => None,
+                    env => Some(env),
+                }),
+            (_, None) => None,
+        };
+
+        env.map(Env::from_str)
+    }
+
+    pub(crate) fn abi(&self) -> Option<Abi> {
+        let env = self.env.unwrap_or_default();
+        let suffix = ["gnu", "musl", "uclibc", "newlib", "android"]
+            .into_iter()
+            .find_map(|libc| env.strip_prefix(libc))
+            .unwrap_or(env)
+            .trim_start_matches('_');
+
+        let abi = match self.arch() {
+            _ if matches!(self.vendor, Some("uwp" | "fortanix")) => self.vendor,
+            _ if matches!(
+                suffix,
+                "abi64"
+                    | "abiv2"
+                    | "abiv2hf"
+                    | "eabi"
+                    | "eabihf"
+                    | "ilp32"
+                    | "llvm"
+                    | "macabi"
+                    | "sim"
+                    | "softfloat"
+                    | "spe"
+                    | "x32"
+            ) =>
+            {
+                Some(suffix)
+            }
+            Arch::Riscv32 if self.arch.starts_with("riscv32e") => Some("ilp32e"),
+            Arch::Arm if matches!(self.os, "freebsd" | "3ds" | "v5") => Some("eabihf"),
+            Arch::Arm if self.vendor != Some("apple") && self.os != "windows" => Some("eabi"),
+            Arch::Mips64 | Arch::Mips64r6 => Some("abi64"),
+            Arch::PowerPc64 if self.os == "aix" => Some("vec-extabi"),
+            Arch::PowerPc64 if self.endian() == Endian::Big && self.env() == Some(Env::Gnu) => {
+                Some("elfv1")
+            }
+            Arch::PowerPc64 => Some("elfv2"),
+            _ if self.env() == Some(Env::Sim) => Some("sim"),
+            _ => None,
+        };
+
+        abi.map(Abi::from_str)
+    }
+
+    pub(crate) fn family(&self) -> Vec<Family> {
+        let mut family = Vec::new();
+        let os = self.os();
+
+        let unix = match os {
+            Os::Aix
+            | Os::Android
+            | Os::Cygwin
+            | Os::Dragonfly
+            | Os::Emscripten
+            | Os::Espidf
+            | Os::FreeBSD
+            | Os::Fuchsia
+            | Os::Haiku
+            | Os::Hurd
+            | Os::IllumOS
+            | Os::iOS
+            | Os::L4re
+            | Os::Linux
+            | Os::Lynxos178
+            | Os::MacOS
+            | Os::Managarm
+            | Os::NetBSD
+            | Os::Nto
+            | Os::Nuttx
+            | Os::OpenBSD
+            | Os::Qurt
+            | Os::Redox
+            | Os::Rtems
+            | Os::Solaris
+            | Os::TvOS
+            | Os::VisionOS
+            | Os::Vita
+            | Os::VxWorks
+            | Os::WatchOS => true,
+            Os::Horizon => self.env() == Some(Env::Newlib),
+            _ => false,
+        };
+        if unix {
+            family.push(Family::Unix);
+        }
+        if os == Os::Windows {
+            family.push(Family::Windows);
+        }
+        if matches!(self.arch(), Arch::Wasm32 | Arch::Wasm64) {
+            family.push(Family::Wasm);
+        }
+
+        family
+    }
+
+    pub(crate) fn to_target(self, triple: &str) -> Target {
+        Target {
+            arch: self.arch(),
+            endian: self.endian(),
+            env: self.env(),
+            abi: self.abi(),
+            os: self.os(),
+            pointer_width: self.pointer_width(),
+            family: self.family(),
+            features: TargetFeatures::new(),
+            atomics: AtomicSupport::default(),
+            vendor: self.vendor(),
+            triple: triple.to_string(),
+        }
+    }
+}
+
+/// Whether the second component of a three component triple is an operating system
+/// (as in `arch-os-env`) rather than a vendor (as in `arch-vendor-os`).
+fn is_os_component(component: &str) -> bool {
+    component != "unknown"
+        && Vendor::from_str(component) == Vendor::Other(component.to_string())
+        && (component.starts_with("wasi") || !matches!(Os::from_str(component), Os::Other(_)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn parse(triple: &str) -> Target {
+        Components::split(triple).unwrap().to_target(triple)
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            Components::split("x86_64-unknown-linux-gnu"),
+            Some(Components {
+                arch: "x86_64",
+                vendor: Some("unknown"),
+                os: "linux",
+                env: Some("gnu"),
+            })
+        );
+        assert_eq!(
+            Components::split("thumbv7m-none-eabi"),
+            Some(Components {
+                arch: "thumbv7m",
+                vendor: None,
+                os: "none",
+                env: Some("eabi"),
+            })
+        );
+        assert_eq!(
+            Components::split("aarch64-apple-darwin"),
+            Some(Components {
+                arch: "aarch64",
+                vendor: Some("apple"),
+                os: "darwin",
+                env: None,
+            })
+        );
+        assert_eq!(Components::split("x86_64"), None);
+        assert_eq!(Components::split("x86_64--linux"), None);
+    }
+
+    #[test]
+    fn quirks() {
+        let target = parse("i686-pc-windows-msvc");
+        assert_eq!(target.arch, Arch::X86);
+        assert_eq!(target.family, vec![Family::Windows]);
+
+        let target = parse("thumbv7m-none-eabi");
+        assert_eq!(target.arch, Arch::Arm);
+        assert_eq!(target.vendor, Vendor::Unknown);
+        assert_eq!(target.os, Os::None);
+        assert_eq!(target.env, None);
+
+        let target = parse("x86_64-apple-darwin");
+        assert_eq!(target.os, Os::MacOS);
+        assert_eq!(target.family, vec![Family::Unix]);
+
+        let target = parse("armv7-unknown-linux-gnueabihf");
+        assert_eq!(target.arch, Arch::Arm);
+        assert_eq!(target.env, Some(Env::Gnu));
+        assert_eq!(target.abi, Some(Abi::EabiHf));
+        assert_eq!(target.pointer_width, PointerWidth::U32);
+
+        let target = parse("arm-linux-androideabi");
+        assert_eq!(target.os, Os::Android);
+        assert_eq!(target.env, None);
+
+        let target = parse("x86_64-unknown-linux-gnux32");
+        assert_eq!(target.pointer_width, PointerWidth::U32);
+        assert_eq!(target.abi, Some(Abi::X32));
+
+        let target = parse("aarch64-pc-windows-gnullvm");
+        assert_eq!(target.env, Some(Env::Gnu));
+        assert_eq!(target.abi, Some(Abi::Llvm));
+
+        let target = parse("mips64el-unknown-linux-gnuabi64");
+        assert_eq!(target.arch, Arch::Mips64);
+        assert_eq!(target.endian, Endian::Little);
+
+        let target = parse("powerpc64-unknown-linux-gnu");
+        assert_eq!(target.endian, Endian::Big);
+
+        let target = parse("aarch64-unknown-nto-qnx710_iosock");
+        assert_eq!(target.env, Some(Env::Nto71Iosock));
+
+        let target = parse("wasm32-wasip1-threads");
+        assert_eq!(target.os, Os::Wasi);
+        assert_eq!(target.env, Some(Env::P1));
+        assert_eq!(target.family, vec![Family::Wasm]);
+
+        let target = parse("wasm32-unknown-emscripten");
+        assert_eq!(target.family, vec![Family::Unix, Family::Wasm]);
+    }
+
+    #[test]
+    fn sub_arch() {
+        for (arch, sub_arch) in [
+            ("thumbv7em", Some(SubArch::ArmV7em)),
+            ("thumbv8m.main", Some(SubArch::ArmV8mMain)),
+            ("armv5te", Some(SubArch::ArmV5te)),
+            ("armebv7r", Some(SubArch::ArmV7r)),
+            ("aarch64v8r", Some(SubArch::ArmV8r)),
+            ("arm64e", Some(SubArch::Arm64e)),
+            ("i586", Some(SubArch::I586)),
+            ("x86_64h", Some(SubArch::X86_64h)),
+            ("riscv64gc", Some(SubArch::Rv64i)),
+            ("riscv32emc", Some(SubArch::Rv32e)),
+            ("riscv32imac", Some(SubArch::Rv32i)),
+            ("mipsisa32r6el", Some(SubArch::Mips32r6)),
+            ("mipsisa64r6", Some(SubArch::Mips64r6)),
+            ("mips64el", None),
+            ("arm", None),
+            ("arm64_32", None),
+            ("arm64ec", None),
+            ("aarch64", None),
+            ("x86_64", None),
+        ] {
+            assert_eq!(SubArch::from_arch_component(arch), sub_arch, "{arch}");
+        }
+    }
+
+    #[test]
+    fn custom_target_path() {
+        let path = std::env::temp_dir().join(format!("x86_64-kernel-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"llvm-target": "x86_64-unknown-none", "arch": "x86_64", "target-pointer-width": "64"}"#,
+        )
+        .unwrap();
+        let env = HashMap::from([("TARGET", path.to_str().unwrap())]);
+        assert_eq!(
+            Triple::from_env(&env).map(|triple| triple.to_string()),
+            Ok("x86_64-unknown-none".to_string())
+        );
+        std::fs::remove_file(path).unwrap();
+
+        // the file stem cargo passes for custom targets is not a triple
+        let env = HashMap::from([("TARGET", "x86_64-kernel")]);
+        assert!(matches!(
+            Triple::from_env(&env),
+            Err(Error::InvalidTargetSpec { .. })
+        ));
+    }
+
+    #[test]
+    fn triple_roundtrip() {
+        for expected in crate::targets::all() {
+            let parsed = Triple::parse(&expected.triple).unwrap();
+            assert_eq!(parsed.to_string(), expected.triple);
+
+            let target = parsed.to_target();
+            assert_eq!(target.arch, expected.arch, "{}", expected.triple);
+            assert_eq!(target.os, expected.os, "{}", expected.triple);
+            assert_eq!(target.env, expected.env, "{}", expected.triple);
+            assert_eq!(target.vendor, expected.vendor, "{}", expected.triple);
+            assert_eq!(target.abi, expected.abi, "{}", expected.triple);
+            assert_eq!(target.endian, expected.endian, "{}", expected.triple);
+            assert_eq!(
+                target.pointer_width, expected.pointer_width,
+                "{}",
+                expected.triple
+            );
+            assert_eq!(target.family, expected.family, "{}", expected.triple);
+        }
+    }
+}
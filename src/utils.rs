@@ -1,20 +1,37 @@
 use std::borrow::Cow;
-use std::env;
+use std::ffi::OsString;
 
-pub(crate) fn build_env_opt(name: &str) -> Option<String> {
-    match env::var(name) {
-        Ok(val) if !val.is_empty() => Some(val),
-        _ => None,
+use crate::{EnvSource, Error};
+
+pub(crate) fn build_env_opt(env: &impl EnvSource, name: &str) -> Result<Option<String>, Error> {
+    match env.var_os(name).map(OsString::into_string) {
+        Some(Ok(val)) if !val.is_empty() => Ok(Some(val)),
+        Some(Ok(_)) | None => Ok(None),
+        Some(Err(value)) => Err(Error::NotUnicode {
+            name: name.to_string(),
+            value,
+        }),
     }
 }
-pub(crate) fn build_env(name: &str) -> String {
-    build_env_opt(name).unwrap_or_else(|| {
-        let crate_name = std::env!("CARGO_CRATE_NAME").replace('_', "-");
-        panic!(
-            "Environment variable {name} not defined, are you using {crate_name} outside of build.rs?",
-        )
+pub(crate) fn build_env(env: &impl EnvSource, name: &str) -> Result<String, Error> {
+    build_env_opt(env, name)?.ok_or_else(|| {
+        // `TARGET` is set for every build script, so its absence means we are running somewhere else.
+        if env.var_os("TARGET").is_none() {
+            Error::NotInBuildScript {
+                name: name.to_string(),
+            }
+        } else {
+            Error::MissingVariables(vec![name.to_string()])
+        }
     })
 }
+/// Checks whether a variable is defined at all, which is how Cargo exposes bare cfgs such as `debug_assertions`.
+pub(crate) fn build_env_flag(env: &impl EnvSource, name: &str) -> bool {
+    env.var_os(name).is_some()
+}
+pub(crate) fn expect_env<T>(result: Result<T, Error>) -> T {
+    result.unwrap_or_else(|err| panic!("{err}"))
+}
 
 macro_rules! define_target_enum {
     (
@@ -63,6 +80,11 @@ macro_rules! define_target_enum {
                     _ => Self::Other(name.to_string()),
                 }
             }
+
+            #[doc = concat!("Iterates over all known [`", stringify!($name), "`] values, i.e. every variant except [`", stringify!($name), "::Other`].")]
+            pub fn all() -> impl Iterator<Item = Self> {
+                [$(Self::$variant),+].into_iter()
+            }
         }
     };
 }
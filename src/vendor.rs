@@ -1,24 +1,51 @@
 use std::fmt;
 
-use crate::utils::{build_env, define_target_enum};
+use crate::{
+    EnvSource, Error, ProcessEnv,
+    utils::{build_env, define_target_enum, expect_env},
+};
 
 define_target_enum! {
     /// The vendor of the target platform, such as the manufacturer of the hardware or the provider of the operating system.
     #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
     #[non_exhaustive]
     pub enum Vendor {
+        /// Advanced Micro Devices
+        Amd => "amd",
         /// Apple Inc.
         Apple => "apple",
+        /// Espressif Systems (ESP32 microcontrollers)
+        Espressif => "espressif",
         /// Fortanix SGX platform
         Fortanix => "fortanix",
+        /// IBM
+        Ibm => "ibm",
+        /// Kyoto Microcomputer Co.
+        Kmc => "kmc",
+        /// MIPS Technologies
+        Mti => "mti",
+        /// Nintendo
+        Nintendo => "nintendo",
         /// NVIDIA Corporation
         Nvidia => "nvidia",
+        /// OpenWrt Linux distribution
+        OpenWrt => "openwrt",
         /// Generic PC platform
         Pc => "pc",
+        /// RISC Zero zkVM
+        Risc0 => "risc0",
         /// Sony platform (e.g., PlayStation)
         Sony => "sony",
+        /// Sun Microsystems (now Oracle)
+        Sun => "sun",
+        /// Unikraft unikernel
+        Unikraft => "unikraft",
         /// Unknown or unspecified vendor
         Unknown => "unknown",
+        /// VEX Robotics
+        Vex => "vex",
+        /// Windows 7 compatible targets
+        Win7 => "win7",
         /// Wind River Systems
         Wrs => "wrs",
         /// Universal Windows Platform
@@ -33,7 +60,17 @@ impl Vendor {
     /// Gets the current target [`Vendor`].
     #[must_use]
     pub fn target() -> Self {
-        Self::from_str(build_env("CARGO_CFG_TARGET_VENDOR"))
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the current target [`Vendor`].
+    pub fn try_target() -> Result<Self, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the target [`Vendor`] from the given [`EnvSource`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Self, Error> {
+        build_env(env, "CARGO_CFG_TARGET_VENDOR").map(Self::from_str)
     }
 }
 
@@ -0,0 +1,281 @@
+use std::fmt;
+
+use crate::{
+    Arch, EnvSource, Error, ProcessEnv, RustFlags, SubArch, Target, TargetFeature, TargetFeatures,
+    Triple,
+    utils::{build_env, define_target_enum, expect_env},
+};
+
+/// Features of the x86-64 baseline, i.e. those every x86-64 CPU supports.
+const BASELINE_FEATURES: &[TargetFeature] =
+    &[TargetFeature::Fxsr, TargetFeature::Sse, TargetFeature::Sse2];
+
+/// Features added by x86-64-v2. `lahfsahf` is omitted as it is not reported by stable compilers.
+const V2_FEATURES: &[TargetFeature] = &[
+    TargetFeature::Cmpxchg16b,
+    TargetFeature::Popcnt,
+    TargetFeature::Sse3,
+    TargetFeature::Ssse3,
+    TargetFeature::Sse4_1,
+    TargetFeature::Sse4_2,
+];
+
+/// Features added by x86-64-v3.
+const V3_FEATURES: &[TargetFeature] = &[
+    TargetFeature::Avx,
+    TargetFeature::Avx2,
+    TargetFeature::Bmi1,
+    TargetFeature::Bmi2,
+    TargetFeature::F16c,
+    TargetFeature::Fma,
+    TargetFeature::Lzcnt,
+    TargetFeature::Movbe,
+    TargetFeature::Xsave,
+];
+
+/// Features added by x86-64-v4.
+const V4_FEATURES: &[TargetFeature] = &[
+    TargetFeature::Avx512bw,
+    TargetFeature::Avx512cd,
+    TargetFeature::Avx512dq,
+    TargetFeature::Avx512f,
+    TargetFeature::Avx512vl,
+];
+
+define_target_enum! {
+    /// Instruction set level of an x86 target, i.e. the microarchitecture level of the x86-64 psABI for
+    /// [`Arch::X86_64`] and the processor generation for [`Arch::X86`].
+    ///
+    /// The known levels are ordered, so a target supports the instructions of all levels below its own.
+    ///
+    /// # Example
+    /// ```rust
+    /// use build_target::{Target, X86Level};
+    ///
+    /// let target = Target::lookup("x86_64h-apple-darwin").unwrap();
+    /// assert_eq!(target.x86_level(), Some(X86Level::V3));
+    /// assert!(target.x86_level().unwrap() >= X86Level::V2);
+    ///
+    /// let target = Target::lookup("i586-unknown-linux-gnu").unwrap();
+    /// assert_eq!(target.x86_level(), Some(X86Level::I586));
+    /// ```
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum X86Level {
+        /// Intel 80386
+        I386 => "i386",
+        /// Intel 80486, adding `cmpxchg` and `bswap`
+        I486 => "i486",
+        /// Intel Pentium, adding `cmpxchg8b`
+        I586 => "i586",
+        /// Intel Pentium Pro, adding `cmov`
+        I686 => "i686",
+        /// x86-64 baseline, guaranteeing SSE2
+        Baseline => "x86-64",
+        /// x86-64-v2, adding SSE4.2, POPCNT and CMPXCHG16B
+        V2 => "x86-64-v2",
+        /// x86-64-v3, adding AVX2, BMI2, FMA and MOVBE
+        V3 => "x86-64-v3",
+        /// x86-64-v4, adding AVX-512
+        V4 => "x86-64-v4",
+    }
+
+    as_str_doc = "String representing this level as passed to `-C target-cpu`, such as `x86-64-v3`.",
+    from_str_doc = "Tries to parse the given string as an [`X86Level`] falling back to [`X86Level::Other`] for unknown values.",
+}
+
+impl X86Level {
+    /// Gets the [`X86Level`] of the current target or [`None`] if it is not an x86 target.
+    ///
+    /// In addition to the enabled target features, the CPU passed through `-C target-cpu` in the rustflags is
+    /// considered.
+    #[must_use]
+    pub fn target() -> Option<Self> {
+        expect_env(Self::try_target())
+    }
+
+    /// Tries to get the [`X86Level`] of the current target. See [`X86Level::target()`].
+    pub fn try_target() -> Result<Option<Self>, Error> {
+        Self::from_env(&ProcessEnv)
+    }
+
+    /// Gets the [`X86Level`] of the target described by the given [`EnvSource`]. See [`X86Level::target()`].
+    pub fn from_env(env: &impl EnvSource) -> Result<Option<Self>, Error> {
+        let arch = Arch::from_env(env)?;
+        let triple = build_env(env, "TARGET")?;
+        let features = TargetFeatures::from_env(env)?;
+        let rustflags = RustFlags::from_env(env)?;
+        Ok(Self::from_parts(
+            &arch,
+            &triple,
+            &features,
+            rustflags.target_cpu.as_deref(),
+        ))
+    }
+
+    /// Gets the [`X86Level`] of the given target or [`None`] if it is not an x86 target.
+    #[must_use]
+    pub fn from_target(target: &Target) -> Option<Self> {
+        Self::from_parts(&target.arch, &target.triple, &target.features, None)
+    }
+
+    fn from_parts(
+        arch: &Arch,
+        triple: &str,
+        features: &TargetFeatures,
+        target_cpu: Option<&str>,
+    ) -> Option<Self> {
+        match arch {
+            Arch::X86_64 => {
+                let level = Self::from_features(features);
+                Some(
+                    match target_cpu.map(Self::from_str).filter(Self::is_x86_64) {
+                        Some(cpu_level) => level.max(cpu_level),
+                        None => level,
+                    },
+                )
+            }
+            Arch::X86 => {
+                let triple_level = Triple::parse(triple)
+                    .ok()
+                    .and_then(|triple| triple.sub_arch())
+                    .and_then(|sub_arch| match sub_arch {
+                        SubArch::I386 => Some(Self::I386),
+                        SubArch::I486 => Some(Self::I486),
+                        SubArch::I586 => Some(Self::I586),
+                        SubArch::I686 => Some(Self::I686),
+                        _ => None,
+                    });
+                let cpu_level = target_cpu.and_then(|cpu| match cpu {
+                    "pentium" | "pentium-mmx" => Some(Self::I586),
+                    "pentiumpro" => Some(Self::I686),
+                    _ => Some(Self::from_str(cpu)).filter(|level| {
+                        matches!(level, Self::I386 | Self::I486 | Self::I586 | Self::I686)
+                    }),
+                });
+                // every processor with FXSR or SSE belongs to the P6 generation or later
+                let feature_level = [TargetFeature::Fxsr, TargetFeature::Sse]
+                    .iter()
+                    .any(|feature| features.contains(feature))
+                    .then_some(Self::I686);
+                [triple_level, cpu_level, feature_level]
+                    .into_iter()
+                    .flatten()
+                    .max()
+                    .or(Some(Self::I386))
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the x86-64 level whose features are all contained in the given features.
+    ///
+    /// Returns [`X86Level::Baseline`] if not even the features of x86-64-v2 are enabled, which is also the case
+    /// for targets such as `x86_64-unknown-none` that disable SSE entirely.
+    #[must_use]
+    pub fn from_features(features: &TargetFeatures) -> Self {
+        let has_all =
+            |required: &[TargetFeature]| required.iter().all(|feature| features.contains(feature));
+        if !has_all(V2_FEATURES) {
+            Self::Baseline
+        } else if !has_all(V3_FEATURES) {
+            Self::V2
+        } else if !has_all(V4_FEATURES) {
+            Self::V3
+        } else {
+            Self::V4
+        }
+    }
+
+    /// Checks whether this is one of the x86-64 levels, i.e. [`X86Level::Baseline`] or above.
+    #[must_use]
+    pub fn is_x86_64(&self) -> bool {
+        matches!(self, Self::Baseline | Self::V2 | Self::V3 | Self::V4)
+    }
+
+    /// Gets the target features guaranteed by this level. This is empty for the levels of 32-bit targets.
+    #[must_use]
+    pub fn features(&self) -> TargetFeatures {
+        let levels: &[&[TargetFeature]] = match self {
+            Self::Baseline => &[BASELINE_FEATURES],
+            Self::V2 => &[BASELINE_FEATURES, V2_FEATURES],
+            Self::V3 => &[BASELINE_FEATURES, V2_FEATURES, V3_FEATURES],
+            Self::V4 => &[BASELINE_FEATURES, V2_FEATURES, V3_FEATURES, V4_FEATURES],
+            _ => &[],
+        };
+        levels.iter().copied().flatten().cloned().collect()
+    }
+}
+
+impl fmt::Display for X86Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Target {
+    /// Gets the [`X86Level`] of this target or [`None`] if it is not an x86 target.
+    /// See [`X86Level::from_target()`].
+    #[must_use]
+    pub fn x86_level(&self) -> Option<X86Level> {
+        X86Level::from_target(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn x86_level(triple: &str) -> Option<X86Level> {
+        Target::lookup(triple).unwrap().x86_level()
+    }
+
+    #[test]
+    fn from_target() {
+        assert_eq!(
+            x86_level("x86_64-unknown-linux-gnu"),
+            Some(X86Level::Baseline)
+        );
+        assert_eq!(x86_level("x86_64-unknown-none"), Some(X86Level::Baseline));
+        // Penryn lacks SSE4.2 and POPCNT
+        assert_eq!(x86_level("x86_64-apple-darwin"), Some(X86Level::Baseline));
+        assert_eq!(x86_level("x86_64h-apple-darwin"), Some(X86Level::V3));
+        assert_eq!(x86_level("i586-unknown-linux-gnu"), Some(X86Level::I586));
+        assert_eq!(x86_level("i686-pc-windows-msvc"), Some(X86Level::I686));
+        assert_eq!(x86_level("i386-apple-ios"), Some(X86Level::I686));
+        assert_eq!(x86_level("aarch64-unknown-linux-gnu"), None);
+    }
+
+    #[test]
+    fn features() {
+        assert_eq!(
+            X86Level::from_features(&X86Level::V4.features()),
+            X86Level::V4
+        );
+        assert_eq!(
+            X86Level::from_features(&X86Level::V3.features()),
+            X86Level::V3
+        );
+        assert_eq!(X86Level::I686.features(), TargetFeatures::new());
+        assert!(X86Level::I686 < X86Level::Baseline);
+    }
+
+    #[test]
+    fn from_env() {
+        let env = HashMap::from([
+            ("TARGET", "x86_64-unknown-linux-gnu"),
+            ("CARGO_CFG_TARGET_ARCH", "x86_64"),
+            ("CARGO_CFG_TARGET_FEATURE", "fxsr,sse,sse2"),
+            ("CARGO_ENCODED_RUSTFLAGS", "-Ctarget-cpu=x86-64-v3"),
+        ]);
+        assert_eq!(X86Level::from_env(&env), Ok(Some(X86Level::V3)));
+
+        let env = HashMap::from([
+            ("TARGET", "i686-unknown-linux-gnu"),
+            ("CARGO_CFG_TARGET_ARCH", "x86"),
+            ("CARGO_ENCODED_RUSTFLAGS", "-Ctarget-cpu=pentium"),
+        ]);
+        assert_eq!(X86Level::from_env(&env), Ok(Some(X86Level::I686)));
+    }
+}
@@ -1,5 +1,9 @@
-use build_target::{target_triple, Target, Arch, Endian, Env, Family, Os, PointerWidth, Vendor};
-use velcro::hash_map;
+use std::ffi::{c_char, c_int, c_long};
+
+use build_target::{
+    host_triple, is_cross_compiling, target_triple, AtomicSupport, BuildConfig, BuildContext,
+    Features, OptLevel, Profile, RustcInfo, Target, TargetFeatures, Triple, Version,
+};
 
 macro_rules! p {
     ($($tokens: tt)*) => {
@@ -8,153 +12,80 @@ macro_rules! p {
 }
 
 fn main() {
-    let map = hash_map! {
-        // Windows targets
-        "i686-pc-windows-msvc": Target {
-            arch: Arch::X86,
-            endian: Endian::Little,
-            env: Some(Env::Msvc),
-            family: vec![Family::Windows],
-            os: Os::Windows,
-            pointer_width: PointerWidth::U32,
-            vendor: Vendor::Pc,
-            triple: "i686-pc-windows-msvc".to_string(),
-        },
-        "i686-pc-windows-gnu": Target {
-            arch: Arch::X86,
-            endian: Endian::Little,
-            env: Some(Env::Gnu),
-            family: vec![Family::Windows],
-            os: Os::Windows,
-            pointer_width: PointerWidth::U32,
-            vendor: Vendor::Pc,
-            triple: "i686-pc-windows-gnu".to_string(),
-        },
-        "x86_64-pc-windows-msvc": Target {
-            arch: Arch::X86_64,
-            endian: Endian::Little,
-            env: Some(Env::Msvc),
-            family: vec![Family::Windows],
-            os: Os::Windows,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Pc,
-            triple: "x86_64-pc-windows-msvc".to_string(),
-        },
-        "x86_64-pc-windows-gnu": Target {
-            arch: Arch::X86_64,
-            endian: Endian::Little,
-            env: Some(Env::Gnu),
-            family: vec![Family::Windows],
-            os: Os::Windows,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Pc,
-            triple: "x86_64-pc-windows-gnu".to_string(),
-        },
-
-        // Linux targets
-        "x86_64-unknown-linux-gnu": Target {
-            arch: Arch::X86_64,
-            endian: Endian::Little,
-            env: Some(Env::Gnu),
-            family: vec![Family::Unix],
-            os: Os::Linux,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Unknown,
-            triple: "x86_64-unknown-linux-gnu".to_string(),
-        },
-        "x86_64-unknown-linux-musl": Target {
-            arch: Arch::X86_64,
-            endian: Endian::Little,
-            env: Some(Env::Musl),
-            family: vec![Family::Unix],
-            os: Os::Linux,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Unknown,
-            triple: "x86_64-unknown-linux-musl".to_string(),
-        },
-        "aarch64-unknown-linux-gnu": Target {
-            arch: Arch::AArch64,
-            endian: Endian::Little,
-            env: Some(Env::Gnu),
-            family: vec![Family::Unix],
-            os: Os::Linux,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Unknown,
-            triple: "aarch64-unknown-linux-gnu".to_string(),
-        },
-        "armv7-unknown-linux-gnueabihf": Target {
-            arch: Arch::Arm,
-            endian: Endian::Little,
-            env: Some(Env::Gnu),
-            family: vec![Family::Unix],
-            os: Os::Linux,
-            pointer_width: PointerWidth::U32,
-            vendor: Vendor::Unknown,
-            triple: "armv7-unknown-linux-gnueabihf".to_string(),
-        },
-
-        // macOS targets
-        "x86_64-apple-darwin": Target {
-            arch: Arch::X86_64,
-            endian: Endian::Little,
-            env: None,
-            family: vec![Family::Unix],
-            os: Os::MacOS,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Apple,
-            triple: "x86_64-apple-darwin".to_string(),
-        },
-        "aarch64-apple-darwin": Target {
-            arch: Arch::AArch64,
-            endian: Endian::Little,
-            env: None,
-            family: vec![Family::Unix],
-            os: Os::MacOS,
-            pointer_width: PointerWidth::U64,
-            vendor: Vendor::Apple,
-            triple: "aarch64-apple-darwin".to_string(),
-        },
-
-        // Others
-        "riscv32imac-unknown-none-elf": Target {
-            arch: Arch::Riscv32,
-            endian: Endian::Little,
-            env: None,
-            family: vec![],
-            os: Os::None,
-            pointer_width: PointerWidth::U32,
-            vendor: Vendor::Unknown,
-            triple: "riscv32imac-unknown-none-elf".to_string(),
-        },
-        "thumbv7m-none-eabi": Target {
-            arch: Arch::Arm,
-            endian: Endian::Little,
-            env: None,
-            family: vec![],
-            os: Os::None,
-            pointer_width: PointerWidth::U32,
-            vendor: Vendor::Unknown,
-            triple: "thumbv7m-none-eabi".to_string(),
-        },
-        "wasm32-unknown-emscripten": Target {
-            arch: Arch::Wasm32,
-            endian: Endian::Little,
-            env: None,
-            family: vec![Family::Unix, Family::Wasm],
-            os: Os::Emscripten,
-            pointer_width: PointerWidth::U32,
-            vendor: Vendor::Unknown,
-            triple: "wasm32-unknown-emscripten".to_string(),
-        },
-    };
-
     let target_str = target_triple();
     let target = Target::current();
+    assert_eq!(Target::try_current().as_ref(), Ok(&target));
 
     p!("{}", target_str);
     p!("{:?}", target);
 
-    if let Some(expected_target) = map.get(target_str.as_str()) {
+    assert_eq!(
+        Target::from_triple(&target_str),
+        Ok(Target {
+            features: TargetFeatures::new(),
+            atomics: AtomicSupport::default(),
+            ..target.clone()
+        }),
+        "❌ Target parsed from triple does not match build environment!"
+    );
+
+    assert_eq!(
+        Target::from_rustc(&target_str).map(|target| Target {
+            features: TargetFeatures::new(),
+            ..target
+        }),
+        Ok(Target {
+            features: TargetFeatures::new(),
+            ..target.clone()
+        }),
+        "❌ Target queried from rustc does not match build environment!"
+    );
+
+    assert_eq!(Profile::current(), Profile::Dev);
+    assert_eq!(Profile::current_base(), Profile::Dev);
+    assert_eq!(Profile::raw_profile(), "debug");
+
+    let config = BuildConfig::current();
+    assert_eq!(config.opt_level, OptLevel::O0);
+    assert!(config.debug_info.is_enabled());
+    assert!(config.debug_assertions);
+    assert_eq!(
+        config.panic.as_str(),
+        std::env::var("CARGO_CFG_PANIC").unwrap()
+    );
+
+    let ctx = BuildContext::current();
+    assert!(std::ptr::eq(ctx, BuildContext::current()));
+    assert_eq!(ctx.target, Target::current());
+    assert_eq!(ctx.profile, Profile::Dev);
+    assert_eq!(ctx.package.name, "test-crate");
+    assert_eq!(
+        ctx.features.iter().collect::<Vec<_>>(),
+        ["default", "my-feature"]
+    );
+    assert!(Features::current().contains("my-feature"));
+
+    let rustc = RustcInfo::current();
+    assert_eq!(rustc.host, host_triple());
+    assert!(rustc.version >= Version::new(1, 85, 0));
+
+    assert_eq!(Triple::current().to_string(), target_triple());
+
+    let host = Target::host();
+    p!("host: {:?}", host);
+    assert_eq!(host.triple, host_triple());
+    assert_eq!(is_cross_compiling(), host.triple != target.triple);
+
+    if !is_cross_compiling() {
+        // the build script runs on the target, so the C types of std match the target
+        let model = target.c_data_model();
+        assert_eq!(usize::from(model.int.size), size_of::<c_int>());
+        assert_eq!(usize::from(model.long.size), size_of::<c_long>());
+        assert_eq!(usize::from(model.pointer.size), size_of::<*const u8>());
+        assert_eq!(model.char_signed, c_char::MIN != 0);
+    }
+
+    if let Some(expected_target) = Target::lookup(&target_str) {
         assert_eq!(
             &target,
             expected_target,